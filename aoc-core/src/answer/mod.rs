@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests;
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    /// Multi-line ASCII art, e.g. letters drawn on a grid of `#` and `.`.
+    Ascii(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Ascii(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Ascii(s) => f.write_str(s),
+        }
+    }
+}
+
+// Answers compare equal to their printed form, which is what gets submitted.
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Text(s) | Answer::Ascii(s) => s == other,
+            _ => {
+                let printed: String = self.to_string();
+                printed == other
+            }
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+// Integers are normalised to the smallest variant that holds them, so that
+// `Answer::from(3u128) == Answer::from(3i32)`.
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i64)
+            }
+        })*
+    };
+}
+
+impl_from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! impl_from_wide_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(small) => Answer::Int(small),
+                    Err(_) => Answer::BigInt(n as i128),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_wide_int!(u64, usize, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(big) => Answer::from(big),
+            // Too large even for `BigInt`; keep the exact digits instead.
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.contains('\n') {
+            Answer::Ascii(s)
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::from(s.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    // Recovers a typed answer from its printed form, e.g. one written to a file.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = s.parse::<i64>() {
+            Ok(Answer::Int(n))
+        } else if let Ok(n) = s.parse::<i128>() {
+            Ok(Answer::BigInt(n))
+        } else if let Ok(n) = s.parse::<u128>() {
+            Ok(Answer::from(n))
+        } else {
+            Ok(Answer::from(s))
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::Answer;
+
+#[test]
+fn integers_use_the_smallest_variant() {
+    assert_eq!(Answer::from(3i32), Answer::Int(3));
+    assert_eq!(Answer::from(3u128), Answer::Int(3));
+    assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+    assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+}
+
+#[test]
+fn display_matches_the_plain_value() {
+    assert_eq!(Answer::from(-42i64).to_string(), "-42");
+    assert_eq!(
+        Answer::from(352509891817881u128).to_string(),
+        "352509891817881"
+    );
+    assert_eq!(Answer::from("ABCDEF").to_string(), "ABCDEF");
+
+    let art: Answer = Answer::from("#..#\n####\n#..#");
+    assert!(art.is_multiline());
+    assert_eq!(art.to_string(), "#..#\n####\n#..#");
+}
+
+#[test]
+fn parses_back_from_printed_form() {
+    let parsed: Answer = "1227775554".parse().unwrap();
+    assert_eq!(parsed, Answer::Int(1227775554));
+
+    let parsed: Answer = "170141183460469231731687303715884105727".parse().unwrap();
+    assert_eq!(parsed, Answer::BigInt(i128::MAX));
+
+    let parsed: Answer = "EHZRLPFE".parse().unwrap();
+    assert_eq!(parsed, Answer::Text("EHZRLPFE".to_string()));
+}
+
+#[test]
+fn compares_against_printed_form() {
+    assert_eq!(Answer::from(357u128), "357");
+    assert_eq!(Answer::from("357"), "357");
+    assert_ne!(Answer::from(356), "357");
+}
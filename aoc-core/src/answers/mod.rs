@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests;
+
+use crate::config::{self, Config};
+use crate::error::ParseError;
+use crate::input::InputLocator;
+use crate::{Answer, Day, Prepared, SolveError};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The confirmed answers to the puzzle inputs of one year, one section per day:
+//
+//     [day01]
+//     part1 = "1023"
+//     part2 = "5899"
+//
+// Since answers belong to inputs, the file lives wherever the year's inputs do, see
+// `InputLocator::year_file`.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+// How an answer compares to the confirmed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+fn parse_section(name: &str) -> Option<u8> {
+    let day: u8 = name.strip_prefix("day")?.parse().ok()?;
+    (1..=25).contains(&day).then_some(day)
+}
+
+impl Answers {
+    pub fn path_for(locator: &InputLocator, year: u16) -> PathBuf {
+        locator.year_file(year, ANSWERS_FILE)
+    }
+
+    pub fn parse(text: &str, path: impl Into<PathBuf>) -> Result<Answers, String> {
+        let config: Config = Config::parse(text).map_err(|e: ParseError| e.to_string())?;
+
+        let mut days: BTreeMap<u8, [Option<String>; 2]> = BTreeMap::new();
+        for name in config.sections() {
+            let Some(day) = parse_section(name) else {
+                return Err(format!("expected sections like [day01], found [{}]", name));
+            };
+            let parts: &mut [Option<String>; 2] = days.entry(day).or_default();
+            for (key, value) in config.section(name) {
+                match key {
+                    "part1" => parts[0] = Some(value.to_string()),
+                    "part2" => parts[1] = Some(value.to_string()),
+                    _ => return Err(format!("unknown key `{}` in [{}]", key, name)),
+                }
+            }
+        }
+
+        Ok(Answers {
+            path: path.into(),
+            days,
+        })
+    }
+
+    // A missing file holds no answers yet.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Answers> {
+        let path: PathBuf = path.into();
+        let text: String = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        Answers::parse(&text, &path).map_err(|e: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?[usize::from(part) - 1].as_deref()
+    }
+
+    pub fn has_day(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    // Records a confirmed answer, returning whether anything changed.
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) -> bool {
+        let slot: &mut Option<String> =
+            &mut self.days.entry(day).or_default()[usize::from(part) - 1];
+        if slot.as_deref() == Some(answer) {
+            return false;
+        }
+        *slot = Some(answer.to_string());
+        true
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Check {
+        match self.get(day, part) {
+            Some(expected) if *answer == expected => Check::Correct,
+            Some(expected) => Check::Wrong(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, self.to_string())
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Confirmed answers to the puzzle inputs. `runner submit` adds correct answers"
+        )?;
+        writeln!(f, "# and `runner verify` checks the solvers against them.")?;
+        for (day, parts) in &self.days {
+            write!(f, "\n[day{:02}]\n", day)?;
+            for (i, part) in parts.iter().enumerate() {
+                if let Some(answer) = part {
+                    writeln!(f, "part{} = {}", i + 1, config::quote(answer))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Re-solves every day of a year that has confirmed answers and panics with a report
+// of every answer that changed. Days without confirmed answers are skipped, so it
+// passes trivially where the inputs (and with them the answers) are not available.
+// `register_days!` turns this into a test of every year crate.
+#[track_caller]
+pub fn check_answers(days: &[Day]) {
+    let Some(year) = days.first().map(|d: &Day| d.year) else {
+        return;
+    };
+    let locator: InputLocator = InputLocator::from_env().expect("workspace should be found");
+    let answers: Answers = Answers::load(Answers::path_for(&locator, year))
+        .unwrap_or_else(|e: io::Error| panic!("cannot load the answers: {}", e));
+
+    let mut failures: Vec<String> = Vec::new();
+    for day in days.iter().filter(|d: &&Day| answers.has_day(d.day)) {
+        let input: String = match locator.read(year, day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                failures.push(format!("{}-{:02}: {}", year, day.day, e));
+                continue;
+            }
+        };
+        let prepared: Box<dyn Prepared + '_> = match day.solver.prepare(&input) {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                failures.push(format!("{}-{:02}: parsing failed: {}", year, day.day, e));
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            if answers.get(day.day, part).is_none() {
+                continue;
+            }
+            let result: Result<Answer, SolveError> = if part == 1 {
+                prepared.part1()
+            } else {
+                prepared.part2()
+            };
+            let problem: String = match result {
+                Ok(answer) => match answers.check(day.day, part, &answer) {
+                    Check::Wrong(expected) => format!("expected {}, got {}", expected, answer),
+                    _ => continue,
+                },
+                Err(e) => format!("failed: {}", e),
+            };
+            failures.push(format!(
+                "{}-{:02} part {}: {}",
+                year, day.day, part, problem
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "answers in {} do not match:\n{}",
+            answers.path().display(),
+            failures.join("\n")
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Answers, Check};
+use crate::Answer;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("aoc_core_answers_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("2025").join("answers.toml")
+}
+
+#[test]
+fn parses_and_checks_answers() {
+    let answers: Answers = Answers::parse(
+        "[day01]\npart1 = \"3\"\npart2 = 6\n\n[day07]\npart2 = \"40\"\n",
+        "a",
+    )
+    .unwrap();
+
+    assert_eq!(answers.get(1, 1), Some("3"));
+    assert_eq!(answers.get(1, 2), Some("6"));
+    assert_eq!(answers.get(7, 1), None);
+    assert!(answers.has_day(7));
+    assert!(!answers.has_day(2));
+
+    assert_eq!(answers.check(1, 1, &Answer::from(3)), Check::Correct);
+    assert_eq!(
+        answers.check(1, 2, &Answer::from(7)),
+        Check::Wrong("6".to_string())
+    );
+    assert_eq!(answers.check(7, 1, &Answer::from(7)), Check::Unknown);
+}
+
+#[test]
+fn rejects_unknown_sections_and_keys() {
+    assert!(
+        Answers::parse("[day26]\npart1 = \"1\"\n", "a")
+            .unwrap_err()
+            .contains("[day26]")
+    );
+    assert!(
+        Answers::parse("[day01]\npart3 = \"1\"\n", "a")
+            .unwrap_err()
+            .contains("part3")
+    );
+    assert!(Answers::parse("part1 = \"1\"\n", "a").is_err());
+}
+
+#[test]
+fn saves_what_it_loads() {
+    let path: PathBuf = temp_file("save");
+
+    let mut answers: Answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.get(1, 1), None);
+    assert!(answers.set(12, 1, "#.#\n\"x\""));
+    assert!(answers.set(3, 2, "173065202451341"));
+    assert!(!answers.set(3, 2, "173065202451341"));
+    answers.save().unwrap();
+
+    let text: String = fs::read_to_string(&path).unwrap();
+    assert!(
+        text.ends_with(
+            "\n[day03]\npart2 = \"173065202451341\"\n\n[day12]\npart1 = \"#.#\\n\\\"x\\\"\"\n"
+        ),
+        "{text}"
+    );
+    assert_eq!(Answers::load(&path).unwrap(), answers);
+
+    fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+}
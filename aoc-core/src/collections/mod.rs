@@ -0,0 +1,3 @@
+mod union_find;
+
+pub use union_find::{Idx, UnionFind};
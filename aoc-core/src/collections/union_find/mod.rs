@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests;
+
+// An integer type elements of a `UnionFind` are numbered with. Narrow types keep the
+// parent links small when there are many elements.
+pub trait Idx: Copy + Eq {
+    fn from_usize(i: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! impl_idx {
+    ($($t:ty),*) => {
+        $(
+            impl Idx for $t {
+                fn from_usize(i: usize) -> Self {
+                    match <$t>::try_from(i) {
+                        Ok(i) => i,
+                        Err(_) => panic!("{} elements do not fit in {}", i, stringify!($t)),
+                    }
+                }
+
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_idx!(u8, u16, u32, u64, usize);
+
+// Disjoint sets over the elements 0..n, which all start in a set of their own. Sets are
+// merged by size and paths are halved on every lookup.
+#[derive(Debug, Clone)]
+pub struct UnionFind<I: Idx = usize> {
+    parent: Vec<I>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl<I: Idx> UnionFind<I> {
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).map(I::from_usize).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    // The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // The number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    // The representative of the set `x` is in.
+    pub fn find(&mut self, x: I) -> I {
+        let mut v: usize = x.to_usize();
+        while self.parent[v].to_usize() != v {
+            let p: usize = self.parent[v].to_usize();
+            self.parent[v] = self.parent[p];
+            v = p;
+        }
+        I::from_usize(v)
+    }
+
+    // Merges the sets of `a` and `b`; false if they already were the same set.
+    pub fn union(&mut self, a: I, b: I) -> bool {
+        let mut ra: usize = self.find(a).to_usize();
+        let mut rb: usize = self.find(b).to_usize();
+        if ra == rb {
+            return false;
+        }
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = I::from_usize(ra);
+        self.size[ra] += self.size[rb];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: I, b: I) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // The number of elements in the set `x` is in.
+    pub fn size(&mut self, x: I) -> usize {
+        let root: I = self.find(x);
+        self.size[root.to_usize()]
+    }
+
+    // Every set with its elements in ascending order, ordered by their smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<I>> + use<I> {
+        let mut slots: Vec<Option<usize>> = vec![None; self.len()];
+        let mut components: Vec<Vec<I>> = Vec::with_capacity(self.count);
+        for x in 0..self.len() {
+            let root: usize = self.find(I::from_usize(x)).to_usize();
+            let slot: usize = *slots[root].get_or_insert_with(|| {
+                components.push(Vec::with_capacity(self.size[root]));
+                components.len() - 1
+            });
+            components[slot].push(I::from_usize(x));
+        }
+        components.into_iter()
+    }
+}
@@ -0,0 +1,58 @@
+use super::UnionFind;
+
+#[test]
+fn starts_with_every_element_on_its_own() {
+    let mut sets: UnionFind = UnionFind::new(3);
+
+    assert_eq!((sets.len(), sets.count()), (3, 3));
+    assert!(!sets.same(0, 1));
+    assert_eq!(sets.size(2), 1);
+    assert_eq!(
+        sets.components().collect::<Vec<Vec<usize>>>(),
+        vec![vec![0], vec![1], vec![2]]
+    );
+
+    let mut empty: UnionFind = UnionFind::new(0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.components().count(), 0);
+}
+
+#[test]
+fn unions_merge_sets() {
+    let mut sets: UnionFind<u16> = UnionFind::new(6);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(4, 1));
+    assert!(sets.union(5, 3));
+    assert!(!sets.union(0, 4));
+
+    assert_eq!(sets.count(), 3);
+    assert!(sets.same(0, 4));
+    assert!(!sets.same(0, 3));
+    assert_eq!(sets.find(4), sets.find(1));
+    assert_eq!((sets.size(1), sets.size(3), sets.size(2)), (3, 2, 1));
+    assert_eq!(
+        sets.components().collect::<Vec<Vec<u16>>>(),
+        vec![vec![0, 1, 4], vec![2], vec![3, 5]]
+    );
+}
+
+#[test]
+fn long_chains_end_up_in_one_set() {
+    let n: u32 = 10_000;
+    let mut sets: UnionFind<u32> = UnionFind::new(n as usize);
+    for x in 1..n {
+        sets.union(x - 1, x);
+    }
+
+    assert_eq!(sets.count(), 1);
+    assert_eq!(sets.size(n / 2), n as usize);
+    assert!(sets.same(0, n - 1));
+}
+
+#[test]
+#[should_panic(expected = "256 elements do not fit in u8")]
+fn rejects_more_elements_than_the_index_type_numbers() {
+    let _ = UnionFind::<u8>::new(257);
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests;
+
+use crate::error::ParseError;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+// The workspace config file, `aoc.toml` next to the workspace `Cargo.toml`.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+// A small subset of TOML, enough for the workspace config and the answer ledgers:
+// `[section]` headers, `key = value` pairs and `#` comments. Values are either quoted
+// strings or bare words such as numbers and booleans, which are kept as written.
+// Keys before the first header live in the section named "".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    sections: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+fn is_bare_key(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// Parses a quoted string at the start of `s`, returning it and the rest of the line.
+fn parse_quoted<'a>(i: usize, line: &str, s: &'a str) -> Result<(String, &'a str), ParseError> {
+    let mut value: String = String::new();
+    let mut chars = s.char_indices().skip(1);
+    while let Some((at, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &s[at + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((at, _)) => {
+                    return Err(ParseError::new(
+                        i,
+                        line,
+                        &s[at - 1..=at],
+                        "an escape like `\\n` or `\\\"`",
+                    ));
+                }
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Err(ParseError::end_of_line(i, line, "a closing `\"`"))
+}
+
+// Writes `value` as a quoted string that `parse` reads back unchanged.
+pub fn quote(value: &str) -> String {
+    let mut quoted: String = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, ParseError> {
+        let mut config: Config = Config::default();
+        let mut section: String = String::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let s: &str = line.trim();
+            if s.is_empty() || s.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = s.strip_prefix('[') {
+                let Some((name, rest)) = header.split_once(']') else {
+                    return Err(ParseError::end_of_line(i, line, "a closing `]`"));
+                };
+                let name: &str = name.trim();
+                if !name.split('.').all(is_bare_key) {
+                    return Err(ParseError::new(i, line, name, "a section name"));
+                }
+                let rest: &str = rest.trim_start();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(ParseError::new(i, line, rest, "end of line"));
+                }
+                section = name.to_string();
+                config.sections.entry(section.clone()).or_default();
+                continue;
+            }
+
+            let Some((key, value)) = s.split_once('=') else {
+                return Err(ParseError::new(
+                    i,
+                    line,
+                    s,
+                    "`key = value` or a `[section]` header",
+                ));
+            };
+            let key: &str = key.trim();
+            if !is_bare_key(key) {
+                return Err(ParseError::new(
+                    i,
+                    line,
+                    key,
+                    "a key made of letters, digits, `_` and `-`",
+                ));
+            }
+
+            let value: &str = value.trim_start();
+            let (value, rest): (String, &str) = if value.starts_with('"') {
+                parse_quoted(i, line, value)?
+            } else {
+                let end: usize = value.find('#').unwrap_or(value.len());
+                let bare: &str = value[..end].trim_end();
+                if bare.is_empty() || bare.contains(char::is_whitespace) {
+                    return Err(ParseError::new(
+                        i,
+                        line,
+                        bare,
+                        "a quoted string, number or boolean",
+                    ));
+                }
+                (bare.to_string(), &value[end..])
+            };
+            let rest: &str = rest.trim_start();
+            if !rest.is_empty() && !rest.starts_with('#') {
+                return Err(ParseError::new(i, line, rest, "end of line"));
+            }
+
+            config.set(&section, key, value);
+        }
+
+        Ok(config)
+    }
+
+    // Loads a config file. A missing file is an empty config; a malformed one is an
+    // `InvalidData` error naming the file and the bad spot.
+    pub fn load(path: &Path) -> io::Result<Config> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|e: ParseError| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.sections.get(section)?.get(key).map(String::as_str)
+    }
+
+    pub fn set(&mut self, section: &str, key: &str, value: impl Into<String>) {
+        self.sections
+            .entry(section.to_string())
+            .or_default()
+            .insert(key.to_string(), value.into());
+    }
+
+    // The names of all sections, in order.
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.sections.keys().map(String::as_str)
+    }
+
+    pub fn section(&self, section: &str) -> impl Iterator<Item = (&str, &str)> {
+        self.sections
+            .get(section)
+            .into_iter()
+            .flatten()
+            .map(|(k, v): (&String, &String)| (k.as_str(), v.as_str()))
+    }
+}
@@ -0,0 +1,92 @@
+use super::{Config, quote};
+use crate::error::ParseError;
+use std::path::Path;
+
+#[test]
+fn parses_sections_keys_and_comments() {
+    let text: &str = r#"
+# Workspace settings
+top = 1
+
+[inputs]
+dir = "../private inputs"   # kept out of git
+
+[fetch]
+base-url = "http://localhost:8000"
+retries = 3
+verbose = true
+"#;
+    let config: Config = Config::parse(text).unwrap();
+
+    assert_eq!(config.get("", "top"), Some("1"));
+    assert_eq!(config.get("inputs", "dir"), Some("../private inputs"));
+    assert_eq!(
+        config.get("fetch", "base-url"),
+        Some("http://localhost:8000")
+    );
+    assert_eq!(config.get("fetch", "retries"), Some("3"));
+    assert_eq!(config.get("fetch", "verbose"), Some("true"));
+    assert_eq!(config.get("fetch", "missing"), None);
+    assert_eq!(config.get("missing", "dir"), None);
+
+    let fetch: Vec<(&str, &str)> = config.section("fetch").collect();
+    assert_eq!(
+        fetch,
+        vec![
+            ("base-url", "http://localhost:8000"),
+            ("retries", "3"),
+            ("verbose", "true")
+        ]
+    );
+}
+
+#[test]
+fn unescapes_quoted_strings() {
+    let config: Config = Config::parse(r#"s = "a \"b\" \\ c\nd # not a comment""#).unwrap();
+    assert_eq!(config.get("", "s"), Some("a \"b\" \\ c\nd # not a comment"));
+}
+
+#[test]
+fn quoted_values_read_back_unchanged() {
+    let value: &str = "a \"b\"\tc\\d\n#..\n.#.";
+    let config: Config = Config::parse(&format!("key = {} # comment", quote(value))).unwrap();
+    assert_eq!(config.get("", "key"), Some(value));
+}
+
+#[test]
+fn later_keys_replace_earlier_ones() {
+    let mut config: Config = Config::parse("[a]\nk = 1\n[b]\n[a]\nk = 2\n").unwrap();
+    assert_eq!(config.get("a", "k"), Some("2"));
+
+    config.set("b", "k", "3");
+    assert_eq!(config.get("b", "k"), Some("3"));
+}
+
+#[test]
+fn reports_malformed_lines() {
+    let error = |text: &str| -> ParseError { Config::parse(text).unwrap_err() };
+
+    let e: ParseError = error("[inputs]\ndir = \"unterminated\n");
+    assert_eq!((e.line, e.expected.as_str()), (2, "a closing `\"`"));
+
+    let e: ParseError = error("just words");
+    assert_eq!((e.line, e.column), (1, 1));
+
+    let e: ParseError = error("[inputs\n");
+    assert_eq!(e.expected, "a closing `]`");
+
+    let e: ParseError = error("key = two words");
+    assert_eq!((e.column, e.found.as_str()), (7, "two words"));
+
+    let e: ParseError = error("key = \"ok\" trailing");
+    assert_eq!((e.column, e.found.as_str()), (12, "trailing"));
+
+    let e: ParseError = error("bad key = 1");
+    assert_eq!(e.found, "bad key");
+}
+
+#[test]
+fn missing_file_is_an_empty_config() {
+    let config: Config = Config::load(Path::new("/definitely/not/here/aoc.toml")).unwrap();
+    assert_eq!(config, Config::default());
+}
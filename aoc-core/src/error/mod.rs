@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    InvalidInput(String),
+    NotImplemented,
+    Panicked(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "malformed input: {e}"),
+            SolveError::InvalidInput(msg) => write!(f, "invalid input: {msg}"),
+            SolveError::NotImplemented => f.write_str("not implemented"),
+            SolveError::Panicked(msg) => write!(f, "solver panicked: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+// A malformed spot in the puzzle input. Line and column are 1-based; the column
+// counts characters, so the caret lines up with what an editor shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+    pub source_line: String,
+}
+
+impl ParseError {
+    // `found` should be a slice of `source_line` (e.g. a token from `split`), which is
+    // how the column is recovered. Anything else is reported at the start of the line.
+    pub fn new(
+        line_index: usize,
+        source_line: &str,
+        found: &str,
+        expected: impl Into<String>,
+    ) -> Self {
+        let start: usize = source_line.as_ptr() as usize;
+        let offset: usize = (found.as_ptr() as usize).wrapping_sub(start);
+        let inside: bool = offset
+            .checked_add(found.len())
+            .is_some_and(|end| end <= source_line.len());
+        let column: usize = if inside && source_line.is_char_boundary(offset) {
+            source_line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        ParseError {
+            line: line_index + 1,
+            column,
+            found: found.to_string(),
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    // For input that stops short, e.g. a missing last field.
+    pub fn end_of_line(line_index: usize, source_line: &str, expected: impl Into<String>) -> Self {
+        let trimmed: &str = source_line.trim_end();
+        ParseError::new(line_index, source_line, &trimmed[trimmed.len()..], expected)
+    }
+
+    // Renders the offending line with a caret under the bad spot:
+    //
+    //   |
+    // 3 | 162,817,abc
+    //   |         ^^^ expected an integer
+    pub fn snippet(&self) -> String {
+        let gutter: usize = self.line.to_string().len();
+        let width: usize = self.found.chars().count().max(1);
+
+        format!(
+            "{:gutter$} |\n{} | {}\n{:gutter$} | {}{} expected {}",
+            "",
+            self.line,
+            self.source_line,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(width),
+            self.expected,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.found.is_empty() {
+            f.write_str("found end of line")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses one token of a line, reporting the token's position if it is not a valid `T`.
+pub fn parse_token<T: FromStr>(
+    line_index: usize,
+    source_line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line_index, source_line, token, expected))
+}
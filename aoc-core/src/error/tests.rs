@@ -0,0 +1,65 @@
+use super::{ParseError, SolveError, parse_token};
+
+#[test]
+fn column_points_at_the_offending_token() {
+    let line: &str = "162,817,abc";
+    let token: &str = line.split(',').nth(2).unwrap();
+    let err: ParseError = ParseError::new(2, line, token, "an integer");
+
+    assert_eq!(err.line, 3);
+    assert_eq!(err.column, 9);
+    assert_eq!(err.found, "abc");
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 9: expected an integer, found \"abc\""
+    );
+}
+
+#[test]
+fn column_counts_characters_not_bytes() {
+    let line: &str = "é,x";
+    let token: &str = line.split(',').nth(1).unwrap();
+    assert_eq!(ParseError::new(0, line, token, "a digit").column, 3);
+}
+
+#[test]
+fn unrelated_found_text_falls_back_to_the_first_column() {
+    let err: ParseError = ParseError::new(0, "1-2", "owned elsewhere", "a range");
+    assert_eq!(err.column, 1);
+}
+
+#[test]
+fn end_of_line_points_past_the_last_character() {
+    let err: ParseError = ParseError::end_of_line(0, "1,2  ", "a third coordinate");
+    assert_eq!(err.column, 4);
+    assert!(err.found.is_empty());
+    assert!(err.to_string().ends_with("found end of line"));
+}
+
+#[test]
+fn snippet_underlines_the_token() {
+    let line: &str = "162,817,abc";
+    let token: &str = &line[8..];
+    let err: ParseError = ParseError::new(9, line, token, "an integer");
+
+    assert_eq!(
+        err.snippet(),
+        "   |\n10 | 162,817,abc\n   |         ^^^ expected an integer"
+    );
+}
+
+#[test]
+fn parse_token_reports_position_on_failure() {
+    let line: &str = "3-x5";
+    let (a, b) = line.split_once('-').unwrap();
+
+    assert_eq!(parse_token::<u64>(0, line, a, "a number"), Ok(3));
+
+    let err: SolveError = parse_token::<u64>(0, line, b, "a number")
+        .unwrap_err()
+        .into();
+    match err {
+        SolveError::Parse(e) => assert_eq!((e.line, e.column, e.found.as_str()), (1, 3, "x5")),
+        other => panic!("unexpected error: {other}"),
+    }
+}
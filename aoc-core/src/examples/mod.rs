@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests;
+
+use crate::config::Config;
+use crate::error::ParseError;
+use crate::input::InputLocator;
+use crate::{Answer, DynSolver, Prepared, SolveError};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The examples of a day, next to its solution. Each section names an input file,
+// relative to the day's directory, and the answers expected for it:
+//
+//     [example1]
+//     input = "example1.in"
+//     part1 = "3"
+//     part2 = "6"
+//
+// Either answer may be left out, e.g. when an example only applies to one part.
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+pub fn parse(text: &str, dir: &Path) -> Result<Vec<Example>, String> {
+    let config: Config = Config::parse(text).map_err(|e: ParseError| e.to_string())?;
+
+    let mut examples: Vec<Example> = Vec::new();
+    for name in config.sections() {
+        if name.is_empty() {
+            return Err("every example needs a `[name]` header".to_string());
+        }
+        let mut input: Option<PathBuf> = None;
+        let mut expected: [Option<String>; 2] = [None, None];
+        for (key, value) in config.section(name) {
+            match key {
+                "input" => input = Some(dir.join(value)),
+                "part1" => expected[0] = Some(value.to_string()),
+                "part2" => expected[1] = Some(value.to_string()),
+                _ => return Err(format!("unknown key `{}` in [{}]", key, name)),
+            }
+        }
+        let Some(input) = input else {
+            return Err(format!("[{}] has no `input`", name));
+        };
+        examples.push(Example {
+            name: name.to_string(),
+            input,
+            expected,
+        });
+    }
+
+    // Sections come back sorted by name; `example10` belongs after `example9`.
+    examples
+        .sort_by(|a: &Example, b: &Example| (a.name.len(), &a.name).cmp(&(b.name.len(), &b.name)));
+    Ok(examples)
+}
+
+pub fn load(dir: &Path) -> io::Result<Vec<Example>> {
+    let path: PathBuf = dir.join(EXAMPLES_FILE);
+    let text: String = fs::read_to_string(&path)
+        .map_err(|e: io::Error| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    parse(&text, dir).map_err(|e: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+// A line diff of two texts, lines only in `expected` marked `-` and lines only in
+// `actual` marked `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+
+    // lcs[i][j]: the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs: Vec<Vec<usize>> = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out: String = String::from("--- expected\n+++ actual\n");
+    let (mut i, mut j): (usize, usize) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push_str(&format!(" {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push_str(&format!("-{}\n", old[i]));
+            i += 1;
+        } else {
+            out.push_str(&format!("+{}\n", new[j]));
+            j += 1;
+        }
+    }
+    out
+}
+
+// Describes what is wrong with one answer, or None if it is the expected one.
+fn mismatch(expected: &str, actual: &Result<Answer, SolveError>) -> Option<String> {
+    match actual {
+        Ok(answer) if *answer == expected => None,
+        Ok(answer) if !answer.is_multiline() && !expected.contains('\n') => {
+            Some(format!("expected {}, got {}", expected, answer))
+        }
+        Ok(answer) => Some(format!(
+            "answers differ\n{}",
+            diff(expected, &answer.to_string())
+        )),
+        Err(e) => Some(format!("expected {}, but it failed: {}", expected, e)),
+    }
+}
+
+// Runs every example and describes each wrong answer; no failures means all passed.
+pub fn run(solver: &dyn DynSolver, examples: &[Example]) -> Vec<String> {
+    let mut failures: Vec<String> = Vec::new();
+    for example in examples {
+        let input: String = match fs::read_to_string(&example.input) {
+            Ok(input) => input,
+            Err(e) => {
+                failures.push(format!(
+                    "{}: cannot read {}: {}",
+                    example.name,
+                    example.input.display(),
+                    e
+                ));
+                continue;
+            }
+        };
+        let prepared: Box<dyn Prepared + '_> = match solver.prepare(&input) {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                failures.push(format!("{}: parsing failed: {}", example.name, e));
+                continue;
+            }
+        };
+
+        for (part, expected) in example.expected.iter().enumerate() {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let actual: Result<Answer, SolveError> = if part == 0 {
+                prepared.part1()
+            } else {
+                prepared.part2()
+            };
+            if let Some(problem) = mismatch(expected, &actual) {
+                failures.push(format!("{} part {}: {}", example.name, part + 1, problem));
+            }
+        }
+    }
+    failures
+}
+
+// Checks a day's solver against its examples file, panicking with a report of every
+// wrong answer. Meant to be called from the day's tests:
+//
+//     #[test]
+//     fn test_day05_readme_examples() {
+//         check_examples(&Day05, 2025, 5);
+//     }
+#[track_caller]
+pub fn check_examples(solver: &dyn DynSolver, year: u16, day: u8) {
+    let dir: PathBuf = InputLocator::from_env()
+        .expect("workspace should be found")
+        .day_dir(year, day);
+    let examples: Vec<Example> =
+        load(&dir).unwrap_or_else(|e: io::Error| panic!("cannot load the examples: {}", e));
+    assert!(
+        !examples.is_empty(),
+        "{} has no examples",
+        dir.join(EXAMPLES_FILE).display()
+    );
+
+    let failures: Vec<String> = run(solver, &examples);
+    if !failures.is_empty() {
+        panic!(
+            "examples of {}-{:02} failed:\n{}",
+            year,
+            day,
+            failures.join("\n")
+        );
+    }
+}
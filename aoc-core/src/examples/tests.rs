@@ -0,0 +1,155 @@
+use super::{Example, diff, load, parse, run};
+use crate::{Answer, SolveError, Solver};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Sums the numbers of the input for part 1 and draws them as a bar chart for part 2.
+struct Bars;
+
+impl Solver for Bars {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input
+            .split_whitespace()
+            .map(|s: &str| {
+                s.parse()
+                    .map_err(|_| SolveError::InvalidInput(s.to_string()))
+            })
+            .collect()
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(parsed.iter().sum::<usize>()))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        if parsed.is_empty() {
+            return Err(SolveError::NotImplemented);
+        }
+        let bars: Vec<String> = parsed.iter().map(|&n: &usize| "#".repeat(n)).collect();
+        Ok(Answer::from(bars.join("\n")))
+    }
+}
+
+fn day_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("aoc_core_examples_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (file, content) in files {
+        fs::write(dir.join(file), content).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn parses_examples_in_natural_order() {
+    let dir: &Path = Path::new("/day");
+    let text: &str = "# comment\n[example10]\ninput = \"ten.in\"\npart2 = \"x\"\n\n[example2]\ninput = \"two.in\"\npart1 = 5\n";
+
+    assert_eq!(
+        parse(text, dir).unwrap(),
+        vec![
+            Example {
+                name: "example2".to_string(),
+                input: dir.join("two.in"),
+                expected: [Some("5".to_string()), None],
+            },
+            Example {
+                name: "example10".to_string(),
+                input: dir.join("ten.in"),
+                expected: [None, Some("x".to_string())],
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejects_malformed_examples() {
+    let dir: &Path = Path::new("/day");
+    assert!(
+        parse("[a]\npart1 = \"1\"\n", dir)
+            .unwrap_err()
+            .contains("no `input`")
+    );
+    assert!(
+        parse("[a]\ninput = \"a.in\"\npart3 = \"1\"\n", dir)
+            .unwrap_err()
+            .contains("unknown key `part3`")
+    );
+    assert!(parse("input = \"a.in\"\n", dir).is_err());
+    assert!(parse("[a\n", dir).is_err());
+}
+
+#[test]
+fn diffs_lines() {
+    assert_eq!(
+        diff("#..\n.#.\n..#", "#..\n.##\n..#\n..."),
+        "--- expected\n+++ actual\n #..\n-.#.\n+.##\n ..#\n+...\n"
+    );
+}
+
+#[test]
+fn runs_examples_and_reports_mismatches() {
+    let dir: PathBuf = day_dir(
+        "run",
+        &[
+            (
+                "examples.toml",
+                "[good]\ninput = \"a.in\"\npart1 = \"3\"\npart2 = \"#\\n##\"\n\n\
+                 [bad]\ninput = \"b.in\"\npart1 = \"4\"\npart2 = \"#\\n#\"\n\n\
+                 [broken]\ninput = \"c.in\"\npart1 = \"0\"\n\n\
+                 [missing]\ninput = \"d.in\"\n",
+            ),
+            ("a.in", "1 2\n"),
+            ("b.in", "1 2\n"),
+            ("c.in", "x\n"),
+        ],
+    );
+    let examples: Vec<Example> = load(&dir).unwrap();
+    assert_eq!(examples.len(), 4);
+
+    let failures: Vec<String> = run(&Bars, &examples);
+    assert_eq!(failures.len(), 4, "{failures:#?}");
+    assert_eq!(failures[0], "bad part 1: expected 4, got 3");
+    assert_eq!(
+        failures[1],
+        "bad part 2: answers differ\n--- expected\n+++ actual\n #\n-#\n+##\n"
+    );
+    assert!(failures[2].starts_with("broken: parsing failed:"));
+    assert!(failures[3].starts_with("missing: cannot read"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn a_failing_part_is_a_mismatch() {
+    let dir: PathBuf = day_dir(
+        "failing",
+        &[
+            (
+                "examples.toml",
+                "[empty]\ninput = \"e.in\"\npart2 = \"#\"\n",
+            ),
+            ("e.in", "\n"),
+        ],
+    );
+
+    let failures: Vec<String> = run(&Bars, &load(&dir).unwrap());
+    assert_eq!(
+        failures,
+        vec!["empty part 2: expected #, but it failed: not implemented"]
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn missing_examples_file_names_the_path() {
+    let dir: PathBuf = day_dir("none", &[]);
+    let error: String = load(&dir).unwrap_err().to_string();
+    assert!(error.contains("examples.toml"), "{error}");
+    fs::remove_dir_all(dir).unwrap();
+}
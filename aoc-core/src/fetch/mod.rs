@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests;
+
+use crate::config::Config;
+use crate::http::{self, HttpError, Response};
+use crate::input::InputLocator;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    BadSession,
+    NotFound(String),
+    Status { status: u16, url: String },
+    Empty(String),
+    Http(HttpError),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session token; set {SESSION_ENV} or `session` in the [site] section of aoc.toml"
+            ),
+            FetchError::BadSession => {
+                f.write_str("the session token was rejected; log in again and copy a fresh one")
+            }
+            FetchError::NotFound(url) => write!(f, "{url} does not exist (yet)"),
+            FetchError::Status { status, url } => write!(f, "{url} answered with HTTP {status}"),
+            FetchError::Empty(url) => write!(f, "{url} returned nothing"),
+            FetchError::Http(e) => e.fmt(f),
+            FetchError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<HttpError> for FetchError {
+    fn from(e: HttpError) -> Self {
+        FetchError::Http(e)
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+// Talks to the Advent of Code site, or whatever `base_url` points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: None,
+        }
+    }
+
+    // Accepts the bare token as well as a pasted `session=...` cookie.
+    pub fn with_session(mut self, token: &str) -> Self {
+        let token: &str = token.trim();
+        let token: &str = token.strip_prefix("session=").unwrap_or(token);
+        self.session = (!token.is_empty()).then(|| token.to_string());
+        self
+    }
+
+    // Environment variables win over the `[site]` section of the config file.
+    pub fn resolve(
+        env_session: Option<String>,
+        env_base_url: Option<String>,
+        config: &Config,
+    ) -> Self {
+        let base_url: String = env_base_url
+            .or_else(|| config.get("site", "base-url").map(str::to_string))
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session: Option<String> =
+            env_session.or_else(|| config.get("site", "session").map(str::to_string));
+
+        let client: Client = Client::new(base_url);
+        match session {
+            Some(token) => client.with_session(&token),
+            None => client,
+        }
+    }
+
+    pub fn from_env(config: &Config) -> Self {
+        let var = |name: &str| {
+            env::var(name)
+                .ok()
+                .filter(|v: &String| !v.trim().is_empty())
+        };
+        Client::resolve(var(SESSION_ENV), var(BASE_URL_ENV), config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn cookie(&self) -> Result<String, FetchError> {
+        match &self.session {
+            Some(token) => Ok(format!("session={token}")),
+            None => Err(FetchError::NoSession),
+        }
+    }
+
+    fn check(&self, url: String, response: Response) -> Result<String, FetchError> {
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(FetchError::NotFound(url)),
+            // The site answers 400 ("please log in") for a missing or expired session
+            // and 500 for a malformed one.
+            400 if response.body.contains("log in") => Err(FetchError::BadSession),
+            500 => Err(FetchError::BadSession),
+            status => Err(FetchError::Status { status, url }),
+        }
+    }
+
+    // A GET with the session cookie, e.g. `client.get("/2025/day/5/input")`.
+    pub fn get(&self, path: &str) -> Result<String, FetchError> {
+        let url: String = format!("{}{}", self.base_url, path);
+        let response: Response = http::get(&url, &[("Cookie", &self.cookie()?)])?;
+        self.check(url, response)
+    }
+
+    // Like `get`, but a session is optional. Without one, pages show what a visitor who
+    // is not logged in sees.
+    pub fn get_page(&self, path: &str) -> Result<String, FetchError> {
+        let url: String = format!("{}{}", self.base_url, path);
+        let cookie: Option<String> = self.cookie().ok();
+        let headers: Vec<(&str, &str)> = cookie
+            .iter()
+            .map(|c: &String| ("Cookie", c.as_str()))
+            .collect();
+        let response: Response = http::get(&url, &headers)?;
+        self.check(url, response)
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        let url: String = format!("{}{}", self.base_url, path);
+        let response: Response = http::post_form(&url, &[("Cookie", &self.cookie()?)], form)?;
+        self.check(url, response)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Writes through a temporary file so an interrupted download never leaves a truncated
+// input behind that would then count as cached.
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp: PathBuf = path.with_extension("download");
+    fs::write(&tmp, content)?;
+    fs::rename(&tmp, path)
+}
+
+// Makes sure the input of one day is on disk. An input that already exists is never
+// downloaded again; only a missing or blank one is fetched, into the locator's
+// default path. A blank file that the locator would still find first is replaced
+// instead, as reads would never get past it.
+pub fn fetch_input(
+    client: &Client,
+    locator: &InputLocator,
+    year: u16,
+    day: u8,
+) -> Result<Fetched, FetchError> {
+    let found: Option<PathBuf> = locator.find(year, day);
+    if let Some(path) = &found
+        && !fs::read_to_string(path)?.trim().is_empty()
+    {
+        return Ok(Fetched::Cached(path.clone()));
+    }
+
+    let path: String = format!("/{year}/day/{day}/input");
+    let input: String = client.get(&path)?;
+    if input.trim().is_empty() {
+        return Err(FetchError::Empty(format!("{}{}", client.base_url, path)));
+    }
+
+    let default: PathBuf = locator.default_path(year, day);
+    let candidates: Vec<PathBuf> = locator.candidates(year, day);
+    let rank = |path: &PathBuf| candidates.iter().position(|c: &PathBuf| c == path);
+    let target: PathBuf = match found {
+        Some(blank) if rank(&blank) < rank(&default) => blank,
+        _ => default,
+    };
+    write_atomically(&target, &input)?;
+    Ok(Fetched::Downloaded(target))
+}
@@ -0,0 +1,157 @@
+use super::{Client, FetchError, Fetched, fetch_input};
+use crate::config::Config;
+use crate::http::stub::{StubServer, response};
+use crate::input::InputLocator;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn workspace(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("aoc_core_fetch_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn resolves_session_and_base_url() {
+    let config: Config =
+        Config::parse("[site]\nsession = \"from-config\"\nbase-url = \"http://localhost:1/\"\n")
+            .unwrap();
+
+    let client: Client = Client::resolve(None, None, &config);
+    assert_eq!(
+        client,
+        Client::new("http://localhost:1").with_session("from-config")
+    );
+
+    let client: Client = Client::resolve(
+        Some("session=from-env".to_string()),
+        Some("http://x".to_string()),
+        &config,
+    );
+    assert_eq!(client, Client::new("http://x").with_session("from-env"));
+
+    let client: Client = Client::resolve(None, None, &Config::default());
+    assert_eq!(client.base_url(), "https://adventofcode.com");
+    assert!(matches!(
+        client.get("/2025/day/1/input"),
+        Err(FetchError::NoSession)
+    ));
+}
+
+#[test]
+fn downloads_once_and_then_uses_the_cache() {
+    let server: StubServer = StubServer::start(vec![response(200, "1-3\n\n2\n")]);
+    let client: Client = Client::new(&server.base_url).with_session("secret");
+    let root: PathBuf = workspace("cache");
+    let locator: InputLocator = InputLocator::new(&root);
+    let expected: PathBuf = root.join("year2025/src/day05/input.in");
+
+    assert_eq!(
+        fetch_input(&client, &locator, 2025, 5).unwrap(),
+        Fetched::Downloaded(expected.clone())
+    );
+    assert_eq!(fs::read_to_string(&expected).unwrap(), "1-3\n\n2\n");
+
+    assert_eq!(
+        fetch_input(&client, &locator, 2025, 5).unwrap(),
+        Fetched::Cached(expected)
+    );
+
+    let requests: Vec<String> = server.requests();
+    assert_eq!(
+        requests.len(),
+        1,
+        "a cached input must not be downloaded again"
+    );
+    assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+}
+
+#[test]
+fn blank_inputs_are_downloaded_again() {
+    let server: StubServer = StubServer::start(vec![response(200, "real input\n")]);
+    let client: Client = Client::new(&server.base_url).with_session("secret");
+    let root: PathBuf = workspace("blank");
+    let locator: InputLocator = InputLocator::new(&root).with_input_dir("private");
+    fs::create_dir_all(root.join("year2025/src/day12")).unwrap();
+    fs::write(root.join("year2025/src/day12/input.in"), "\n").unwrap();
+
+    let downloaded: PathBuf = root.join("private/2025/day12.in");
+    assert_eq!(
+        fetch_input(&client, &locator, 2025, 12).unwrap(),
+        Fetched::Downloaded(downloaded.clone())
+    );
+    assert_eq!(fs::read_to_string(downloaded).unwrap(), "real input\n");
+}
+
+#[test]
+fn blank_inputs_found_first_are_replaced() {
+    let server: StubServer = StubServer::start(vec![response(200, "real input\n")]);
+    let client: Client = Client::new(&server.base_url).with_session("secret");
+    let root: PathBuf = workspace("blank_txt");
+    let locator: InputLocator = InputLocator::new(&root);
+    let blank: PathBuf = root.join("year2025/src/day12/input.txt");
+    fs::create_dir_all(blank.parent().unwrap()).unwrap();
+    fs::write(&blank, "").unwrap();
+
+    // Saving to `input.in` would leave the blank `input.txt` in the way of every read.
+    assert_eq!(
+        fetch_input(&client, &locator, 2025, 12).unwrap(),
+        Fetched::Downloaded(blank.clone())
+    );
+    assert_eq!(locator.read(2025, 12).unwrap(), "real input\n");
+    assert_eq!(
+        fetch_input(&client, &locator, 2025, 12).unwrap(),
+        Fetched::Cached(blank)
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn cached_inputs_need_no_session() {
+    let root: PathBuf = workspace("no_session");
+    fs::create_dir_all(root.join("inputs/2025")).unwrap();
+    fs::write(root.join("inputs/2025/day01.in"), "L1\n").unwrap();
+
+    let client: Client = Client::new("http://127.0.0.1:9");
+    let result: Fetched = fetch_input(&client, &InputLocator::new(&root), 2025, 1).unwrap();
+    assert!(matches!(result, Fetched::Cached(_)));
+}
+
+#[test]
+fn reports_site_errors() {
+    let server: StubServer = StubServer::start(vec![
+        response(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        ),
+        response(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        ),
+        response(503, "busy"),
+    ]);
+    let client: Client = Client::new(&server.base_url).with_session("secret");
+    let root: PathBuf = workspace("errors");
+    let locator: InputLocator = InputLocator::new(&root);
+
+    assert!(matches!(
+        fetch_input(&client, &locator, 2025, 25),
+        Err(FetchError::NotFound(_))
+    ));
+    assert!(matches!(
+        fetch_input(&client, &locator, 2025, 25),
+        Err(FetchError::BadSession)
+    ));
+    assert!(matches!(
+        fetch_input(&client, &locator, 2025, 25),
+        Err(FetchError::Status { status: 503, .. })
+    ));
+    assert!(
+        locator.find(2025, 25).is_none(),
+        "failed downloads leave nothing behind"
+    );
+}
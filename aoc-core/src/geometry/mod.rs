@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests;
+
+use crate::grid::{Grid, Pos};
+use std::fmt;
+use std::ops::Range;
+
+// The distinct coordinates that matter along one axis, sorted, so a huge sparse axis
+// can be indexed by position instead. Between two neighbouring coordinates lies a slab.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedAxis<T> {
+    values: Vec<T>,
+}
+
+impl<T: Ord + Copy> CompressedAxis<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values: Vec<T> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        CompressedAxis { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    // The index of a coordinate that is on the axis.
+    pub fn index(&self, value: T) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    // The index of the first coordinate at or after `value`, `len()` if there is none.
+    pub fn lower_bound(&self, value: T) -> usize {
+        self.values.partition_point(|&v: &T| v < value)
+    }
+
+    // The slab `k` that has `values[k] <= value < values[k + 1]`.
+    pub fn slab(&self, value: T) -> Option<usize> {
+        let after: usize = self.values.partition_point(|&v: &T| v <= value);
+        (after > 0 && after < self.values.len()).then(|| after - 1)
+    }
+}
+
+// Sums over the cells of a grid, so that the sum of any rectangle of it takes constant
+// time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSums {
+    width: usize,
+    height: usize,
+    // `sums[y * (width + 1) + x]` is the sum of the cells above and left of (x, y).
+    sums: Vec<i64>,
+}
+
+impl PrefixSums {
+    pub fn new<T>(grid: &Grid<T>, value: impl Fn(&T) -> i64) -> Self {
+        let (width, height): (usize, usize) = (grid.width(), grid.height());
+        let stride: usize = width + 1;
+        let mut sums: Vec<i64> = vec![0; stride * (height + 1)];
+
+        for ((x, y), cell) in grid.cells() {
+            sums[(y + 1) * stride + x + 1] =
+                value(cell) + sums[y * stride + x + 1] + sums[(y + 1) * stride + x]
+                    - sums[y * stride + x];
+        }
+
+        PrefixSums {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    // The sum of the cells with x in `xs` and y in `ys`.
+    pub fn sum(&self, xs: Range<usize>, ys: Range<usize>) -> i64 {
+        assert!(
+            xs.end <= self.width && ys.end <= self.height,
+            "{:?} x {:?} is outside a {}x{} grid",
+            xs,
+            ys,
+            self.width,
+            self.height
+        );
+        if xs.is_empty() || ys.is_empty() {
+            return 0;
+        }
+
+        let stride: usize = self.width + 1;
+        self.sums[ys.end * stride + xs.end]
+            - self.sums[ys.start * stride + xs.end]
+            - self.sums[ys.end * stride + xs.start]
+            + self.sums[ys.start * stride + xs.start]
+    }
+}
+
+// A polygon whose edges are all horizontal or vertical, e.g. a loop of grid tiles,
+// rasterized on the compressed grid of its vertex coordinates. Each axis alternates
+// between points and open slabs: position 2k is the coordinate `values[k]`, position
+// 2k + 1 the slab after it. That way lines and points on the outline count too, and
+// asking whether a rectangle lies inside is a single prefix-sum lookup.
+#[derive(Debug, Clone)]
+pub struct OrthogonalPolygon<T> {
+    xs: CompressedAxis<T>,
+    ys: CompressedAxis<T>,
+    sums: PrefixSums,
+}
+
+impl<T: Ord + Copy + fmt::Debug> OrthogonalPolygon<T> {
+    // The vertices in order around the outline; the last connects back to the first.
+    pub fn new(vertices: &[(T, T)]) -> Result<Self, String> {
+        let xs: CompressedAxis<T> = CompressedAxis::new(vertices.iter().map(|&(x, _)| x));
+        let ys: CompressedAxis<T> = CompressedAxis::new(vertices.iter().map(|&(_, y)| y));
+
+        // Vertical edges flip the slabs they span between inside and outside, for every
+        // slab to their right. Horizontal edges only need checking.
+        let mut flips: Vec<Vec<Range<usize>>> = vec![Vec::new(); xs.len()];
+        for (i, &(x1, y1)) in vertices.iter().enumerate() {
+            let (x2, y2) = vertices[(i + 1) % vertices.len()];
+            if x1 == x2 {
+                let (a, b): (usize, usize) = (ys.lower_bound(y1), ys.lower_bound(y2));
+                flips[xs.lower_bound(x1)].push(a.min(b)..a.max(b));
+            } else if y1 != y2 {
+                return Err(format!(
+                    "the edge from {:?} to {:?} is neither horizontal nor vertical",
+                    (x1, y1),
+                    (x2, y2)
+                ));
+            }
+        }
+
+        let width: usize = (2 * xs.len()).saturating_sub(1);
+        let height: usize = (2 * ys.len()).saturating_sub(1);
+        let mut inside: Grid<bool> = Grid::new(width, height, false);
+        let mut column: Vec<bool> = vec![false; ys.len().saturating_sub(1)];
+        for (k, flips) in flips.iter().enumerate().take(xs.len().saturating_sub(1)) {
+            for range in flips {
+                for slab in &mut column[range.clone()] {
+                    *slab = !*slab;
+                }
+            }
+            for (j, &slab) in column.iter().enumerate() {
+                inside[(2 * k + 1, 2 * j + 1)] = slab;
+            }
+        }
+
+        // Lines and points belong to the closed polygon when an open slab next to them
+        // does; slabs only ever border lines and points.
+        let mut closed: Grid<bool> = Grid::new(width, height, false);
+        for pos in inside.positions() {
+            closed[pos] = inside[pos] || inside.neighbors8(pos).any(|n: Pos| inside[n]);
+        }
+
+        Ok(OrthogonalPolygon {
+            xs,
+            ys,
+            sums: PrefixSums::new(&closed, |&cell: &bool| i64::from(cell)),
+        })
+    }
+
+    pub fn xs(&self) -> &CompressedAxis<T> {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &CompressedAxis<T> {
+        &self.ys
+    }
+
+    // The position of a coordinate on the raster: even on a vertex coordinate, odd in
+    // the slab after one. `None` outside the polygon's bounding box.
+    fn raster(axis: &CompressedAxis<T>, value: T) -> Option<usize> {
+        let k: usize = axis.lower_bound(value);
+        match axis.values().get(k) {
+            Some(&v) if v == value => Some(2 * k),
+            Some(_) if k > 0 => Some(2 * k - 1),
+            _ => None,
+        }
+    }
+
+    // Whether the rectangle with opposite corners `a` and `b` lies inside the polygon,
+    // where both outlines count as inside. The rectangle may be a line or a point.
+    pub fn contains_rect(&self, a: (T, T), b: (T, T)) -> bool {
+        let (Some(x0), Some(x1), Some(y0), Some(y1)) = (
+            Self::raster(&self.xs, a.0.min(b.0)),
+            Self::raster(&self.xs, a.0.max(b.0)),
+            Self::raster(&self.ys, a.1.min(b.1)),
+            Self::raster(&self.ys, a.1.max(b.1)),
+        ) else {
+            return false;
+        };
+
+        let area: usize = (x1 - x0 + 1) * (y1 - y0 + 1);
+        self.sums.sum(x0..x1 + 1, y0..y1 + 1) == area as i64
+    }
+
+    pub fn contains(&self, point: (T, T)) -> bool {
+        self.contains_rect(point, point)
+    }
+}
@@ -0,0 +1,81 @@
+use super::{CompressedAxis, OrthogonalPolygon, PrefixSums};
+use crate::grid::Grid;
+
+#[test]
+fn compresses_an_axis() {
+    let axis: CompressedAxis<i64> = CompressedAxis::new([40, -5, 7, 40, 1_000_000]);
+
+    assert_eq!(axis.values(), [-5, 7, 40, 1_000_000]);
+    assert_eq!(axis.index(40), Some(2));
+    assert_eq!(axis.index(41), None);
+    assert_eq!(axis.lower_bound(8), 2);
+    assert_eq!(axis.lower_bound(2_000_000), 4);
+    assert_eq!(axis.slab(-5), Some(0));
+    assert_eq!(axis.slab(39), Some(1));
+    assert_eq!(axis.slab(999_999), Some(2));
+    assert_eq!(axis.slab(1_000_000), None);
+    assert_eq!(axis.slab(-6), None);
+}
+
+#[test]
+fn sums_rectangles() {
+    let grid: Grid<i64> = Grid::parse("123\n456\n789", |c: char| {
+        c.to_digit(10).map(i64::from).ok_or("a digit")
+    })
+    .unwrap();
+    let sums: PrefixSums = PrefixSums::new(&grid, |&n: &i64| n);
+
+    assert_eq!(sums.sum(0..3, 0..3), 45);
+    assert_eq!(sums.sum(1..3, 1..3), 5 + 6 + 8 + 9);
+    assert_eq!(sums.sum(0..1, 2..3), 7);
+    assert_eq!(sums.sum(2..2, 0..3), 0);
+}
+
+#[test]
+#[should_panic(expected = "outside a 3x1 grid")]
+fn sums_reject_rectangles_outside_the_grid() {
+    let sums: PrefixSums = PrefixSums::new(&Grid::new(3, 1, 1), |&n: &i64| n);
+    sums.sum(0..4, 0..1);
+}
+
+// An L: the square (0, 0)-(4, 2) with (0, 2)-(2, 4) below it.
+fn ell() -> OrthogonalPolygon<i32> {
+    OrthogonalPolygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]).unwrap()
+}
+
+#[test]
+fn rectangles_inside_a_polygon() {
+    let ell: OrthogonalPolygon<i32> = ell();
+
+    assert!(ell.contains_rect((0, 0), (4, 2)));
+    assert!(ell.contains_rect((2, 4), (0, 0)));
+    assert!(ell.contains_rect((1, 1), (3, 2)));
+    assert!(!ell.contains_rect((0, 0), (4, 4)));
+    assert!(!ell.contains_rect((1, 1), (3, 3)));
+    assert!(!ell.contains_rect((0, 0), (5, 1)));
+}
+
+#[test]
+fn outlines_count_as_inside() {
+    let ell: OrthogonalPolygon<i32> = ell();
+
+    assert!(ell.contains((2, 2)));
+    assert!(ell.contains((3, 2)));
+    assert!(ell.contains((1, 1)));
+    assert!(!ell.contains((3, 3)));
+    assert!(!ell.contains((-1, 0)));
+    assert!(ell.contains_rect((2, 2), (4, 2)));
+    assert!(ell.contains_rect((2, 0), (2, 4)));
+    assert!(!ell.contains_rect((2, 3), (4, 3)));
+}
+
+#[test]
+fn rejects_slanted_edges() {
+    assert_eq!(
+        OrthogonalPolygon::new(&[(0, 0), (2, 0), (0, 2)]).unwrap_err(),
+        "the edge from (2, 0) to (0, 2) is neither horizontal nor vertical"
+    );
+
+    let empty: OrthogonalPolygon<i32> = OrthogonalPolygon::new(&[]).unwrap();
+    assert!(!empty.contains((0, 0)));
+}
@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod tests;
+
+use crate::error::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A position in a grid as (x, y): the column, then the row, counted from the top left.
+pub type Pos = (usize, usize);
+
+// Steps to the orthogonal neighbours, clockwise from up.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Steps to all eight neighbours, clockwise from up.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Rows become columns: the cell at (x, y) moves to (y, x).
+    pub fn transpose(&self) -> Grid<T> {
+        let cells: Vec<T> = (0..self.width)
+            .flat_map(|x: usize| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    // A quarter turn clockwise: the left column becomes the top row.
+    pub fn rotate_right(&self) -> Grid<T> {
+        let cells: Vec<T> = (0..self.width)
+            .flat_map(|x: usize| self.column(x).rev().cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    // A quarter turn counterclockwise: the right column becomes the top row.
+    pub fn rotate_left(&self) -> Grid<T> {
+        let cells: Vec<T> = (0..self.width)
+            .rev()
+            .flat_map(|x: usize| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // One cell per character, one row per line; blank lines are skipped, as is trailing
+    // whitespace. `cell` maps a character to its cell or says what it expected instead,
+    // e.g. "`#` or `.`".
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut width: Option<usize> = None;
+        let mut height: usize = 0;
+        let mut cells: Vec<T> = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line: &str = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut count: usize = 0;
+            for (offset, c) in line.char_indices() {
+                if width == Some(count) {
+                    let found: &str = &line[offset..];
+                    let expected: String = format!("a row of {} cells", count);
+                    return Err(ParseError::new(i, line, found, expected));
+                }
+                let value: T = cell(c).map_err(|e: &str| {
+                    ParseError::new(i, line, &line[offset..][..c.len_utf8()], e)
+                })?;
+                cells.push(value);
+                count += 1;
+            }
+            match width {
+                Some(width) if count < width => {
+                    let expected: String = format!("a row of {} cells", width);
+                    return Err(ParseError::end_of_line(i, line, expected));
+                }
+                _ => width = Some(count),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Treats the grid as repeating forever in every direction. Panics on an empty grid.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> &T {
+        let x: usize = x.rem_euclid(self.width as isize) as usize;
+        let y: usize = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    // The position one step of `(dx, dy)` away, if it is still inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x: usize = x
+            .checked_add_signed(dx)
+            .filter(|&x: &usize| x < self.width)?;
+        let y: usize = y
+            .checked_add_signed(dy)
+            .filter(|&y: &usize| y < self.height)?;
+        Some((x, y))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS4
+            .into_iter()
+            .filter_map(move |step: (isize, isize)| self.offset(pos, step))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |step: (isize, isize)| self.offset(pos, step))
+    }
+
+    // Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width: usize = self.width;
+        (0..self.height).flat_map(move |y: usize| (0..width).map(move |x: usize| (x, y)))
+    }
+
+    // Every cell with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    // The first position, row by row, whose cell matches.
+    pub fn find(&self, matches: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.cells()
+            .find(|(_, cell): &(Pos, &T)| matches(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all(&self, matches: impl Fn(&T) -> bool) -> impl Iterator<Item = Pos> {
+        self.cells()
+            .filter(move |(_, cell): &(Pos, &T)| matches(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a zero width, which only an empty grid has.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} of a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {:?} is outside a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height): (usize, usize) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {:?} is outside a {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+// Writes the grid back as text, one line per row. Map the cells to `char` first to get
+// the puzzle's own notation back.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use super::{Grid, Pos};
+use crate::error::ParseError;
+
+fn letters() -> Grid<char> {
+    Grid::parse("abc\ndef\n", Ok).unwrap()
+}
+
+fn roll(c: char) -> Result<bool, &'static str> {
+    match c {
+        '@' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("`@` or `.`"),
+    }
+}
+
+#[test]
+fn parses_and_writes_back_text() {
+    let grid: Grid<bool> = Grid::parse("\n.@.\r\n@@.\n\n", roll).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert!(grid[(1, 0)]);
+    assert!(!grid[(2, 1)]);
+    assert_eq!(
+        grid.map(|&r: &bool| if r { '@' } else { '.' }).to_string(),
+        ".@.\n@@."
+    );
+
+    let empty: Grid<bool> = Grid::parse("", roll).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_string(), "");
+}
+
+#[test]
+fn reports_unexpected_cells_and_ragged_rows() {
+    let e: ParseError = Grid::parse(".@\n.x", roll).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "x"));
+    assert_eq!(e.expected, "`@` or `.`");
+
+    let e: ParseError = Grid::parse("..\n...", roll).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "."));
+    assert_eq!(e.expected, "a row of 2 cells");
+
+    let e: ParseError = Grid::parse("..\n.", roll).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, ""));
+}
+
+#[test]
+fn ignores_trailing_whitespace() {
+    let grid: Grid<bool> = Grid::parse(".@.  \n@@.\t\r\n..@\n", roll).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert!(grid[(2, 2)]);
+}
+
+#[test]
+fn accessors_check_or_wrap_bounds() {
+    let mut grid: Grid<char> = letters();
+
+    assert_eq!(grid.get((2, 1)), Some(&'f'));
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, 2)), None);
+    assert_eq!(grid.get_wrapping((-1, -1)), &'f');
+    assert_eq!(grid.get_wrapping((4, 5)), &'e');
+    assert_eq!(grid.offset((0, 1), (1, -1)), Some((1, 0)));
+    assert_eq!(grid.offset((0, 1), (-1, 0)), None);
+    assert_eq!(grid.offset((0, 1), (0, 1)), None);
+
+    grid[(1, 1)] = 'E';
+    *grid.get_mut((0, 0)).unwrap() = 'A';
+    assert_eq!(grid.to_string(), "Abc\ndEf");
+}
+
+#[test]
+#[should_panic(expected = "outside a 3x2 grid")]
+fn indexing_outside_panics() {
+    let _ = letters()[(0, 2)];
+}
+
+#[test]
+fn neighbors_stay_inside() {
+    let grid: Grid<char> = letters();
+
+    let corner: Vec<Pos> = grid.neighbors4((0, 0)).collect();
+    assert_eq!(corner, vec![(1, 0), (0, 1)]);
+    let edge: Vec<char> = grid.neighbors8((1, 0)).map(|p: Pos| grid[p]).collect();
+    assert_eq!(edge, vec!['c', 'f', 'e', 'd', 'a']);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+}
+
+#[test]
+fn finds_cells_row_by_row() {
+    let grid: Grid<bool> = Grid::parse(".@.\n@@.", roll).unwrap();
+
+    assert_eq!(grid.find(|&r: &bool| r), Some((1, 0)));
+    assert_eq!(grid.find(|_: &bool| false), None);
+    let rolls: Vec<Pos> = grid.find_all(|&r: &bool| r).collect();
+    assert_eq!(rolls, vec![(1, 0), (0, 1), (1, 1)]);
+}
+
+#[test]
+fn views_rows_and_columns() {
+    let grid: Grid<char> = letters();
+
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.rows().count(), 2);
+    let column: String = grid.column(2).collect();
+    assert_eq!(column, "cf");
+    let positions: Vec<Pos> = grid.positions().collect();
+    assert_eq!(
+        positions,
+        vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid: Grid<char> = letters();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    assert_eq!(
+        grid.rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right(),
+        grid
+    );
+    assert_eq!(Grid::new(2, 1, '.').transpose().to_string(), ".\n.");
+}
@@ -0,0 +1,265 @@
+#[cfg(test)]
+pub(crate) mod stub;
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Output, Stdio};
+use std::time::Duration;
+
+// A minimal HTTP client. Plain `http://` URLs are spoken to directly over TCP, which
+// is all the local test servers need; `https://` goes through the `curl` binary, as
+// the standard library has no TLS.
+
+pub const USER_AGENT: &str = "github.com/nico-vromans/Advent-of-Code runner";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    InvalidUrl(String),
+    Io(io::Error),
+    Malformed(String),
+    Curl(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::InvalidUrl(url) => write!(f, "invalid URL `{url}`"),
+            HttpError::Io(e) => write!(f, "connection failed: {e}"),
+            HttpError::Malformed(msg) => write!(f, "malformed response: {msg}"),
+            HttpError::Curl(msg) => write!(f, "curl failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, HttpError> {
+    request("GET", url, headers, None)
+}
+
+pub fn post_form(
+    url: &str,
+    headers: &[(&str, &str)],
+    form: &[(&str, &str)],
+) -> Result<Response, HttpError> {
+    request("POST", url, headers, Some(&form_urlencode(form)))
+}
+
+// `application/x-www-form-urlencoded`: unreserved characters are kept, spaces become
+// `+` and everything else is percent-encoded.
+pub fn form_urlencode(form: &[(&str, &str)]) -> String {
+    let encode = |s: &str| -> String {
+        let mut out: String = String::with_capacity(s.len());
+        for b in s.bytes() {
+            match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    out.push(b as char)
+                }
+                b' ' => out.push('+'),
+                _ => out.push_str(&format!("%{:02X}", b)),
+            }
+        }
+        out
+    };
+
+    form.iter()
+        .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    form: Option<&str>,
+) -> Result<Response, HttpError> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        plain_request(method, url, rest, headers, form)
+    } else if url.starts_with("https://") {
+        curl_request(method, url, headers, form)
+    } else {
+        Err(HttpError::InvalidUrl(url.to_string()))
+    }
+}
+
+fn plain_request(
+    method: &str,
+    url: &str,
+    rest: &str,
+    headers: &[(&str, &str)],
+    form: Option<&str>,
+) -> Result<Response, HttpError> {
+    let (authority, path): (&str, &str) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    if authority.is_empty() {
+        return Err(HttpError::InvalidUrl(url.to_string()));
+    }
+    let address: String = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+
+    let mut stream: TcpStream = TcpStream::connect(&address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut head: String = format!(
+        "{method} {path} HTTP/1.1\r\nHost: {authority}\r\nUser-Agent: {USER_AGENT}\r\nConnection: close\r\n"
+    );
+    for (name, value) in headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = form {
+        head.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    if let Some(body) = form {
+        stream.write_all(body.as_bytes())?;
+    }
+
+    read_response(BufReader::new(stream))
+}
+
+// Parses an HTTP/1.1 response, honouring `Content-Length` and chunked encoding and
+// otherwise reading until the server closes the connection.
+pub fn read_response(mut reader: impl BufRead) -> Result<Response, HttpError> {
+    let mut line: String = String::new();
+    reader.read_line(&mut line)?;
+    let status: u16 = line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s: &str| s.parse().ok())
+        .ok_or_else(|| HttpError::Malformed(format!("bad status line {:?}", line.trim_end())))?;
+
+    let mut content_length: Option<usize> = None;
+    let mut chunked: bool = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(HttpError::Malformed("headers cut short".to_string()));
+        }
+        let header: &str = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value: &str = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().ok();
+            } else if name.eq_ignore_ascii_case("transfer-encoding")
+                && value.eq_ignore_ascii_case("chunked")
+            {
+                chunked = true;
+            }
+        }
+    }
+
+    let mut body: Vec<u8> = Vec::new();
+    if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let size_field: &str = line.trim().split(';').next().unwrap_or("");
+            let size: usize = usize::from_str_radix(size_field, 16)
+                .map_err(|_| HttpError::Malformed(format!("bad chunk size {:?}", line.trim())))?;
+            if size == 0 {
+                break;
+            }
+            let start: usize = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            line.clear();
+            reader.read_line(&mut line)?;
+        }
+    } else if let Some(length) = content_length {
+        body.resize(length, 0);
+        reader.read_exact(&mut body)?;
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+
+    let body: String = String::from_utf8(body)
+        .map_err(|_| HttpError::Malformed("body is not UTF-8".to_string()))?;
+    Ok(Response { status, body })
+}
+
+fn curl_request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    form: Option<&str>,
+) -> Result<Response, HttpError> {
+    let mut curl: Command = Command::new("curl");
+    curl.args([
+        "--silent",
+        "--show-error",
+        "--max-time",
+        "30",
+        "--request",
+        method,
+    ]);
+    curl.args(["--user-agent", USER_AGENT]);
+    // The status code goes on a line of its own after the body.
+    curl.args(["--write-out", "\n%{http_code}"]);
+    // Headers are passed on stdin so the session cookie never shows up in `ps`.
+    curl.args(["--header", "@-"]);
+    if let Some(body) = form {
+        curl.args(["--data", body]);
+    }
+    curl.arg(url);
+    curl.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let spawn_error = |e: io::Error| HttpError::Curl(format!("cannot run curl: {e}"));
+    let mut child: Child = curl.spawn().map_err(spawn_error)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+    }
+    let output: Output = child.wait_with_output().map_err(spawn_error)?;
+    if !output.status.success() {
+        return Err(HttpError::Curl(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let stdout: String = String::from_utf8(output.stdout)
+        .map_err(|_| HttpError::Malformed("body is not UTF-8".to_string()))?;
+    let (body, status): (&str, &str) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| HttpError::Malformed("missing status code".to_string()))?;
+    let status: u16 = status
+        .trim()
+        .parse()
+        .map_err(|_| HttpError::Malformed(format!("bad status code {status:?}")))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
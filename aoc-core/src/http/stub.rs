@@ -0,0 +1,71 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// A local HTTP server for tests. It answers every request with the next canned
+// response (repeating the last one) and records what it was sent.
+pub struct StubServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+// Reads one request, records it and only then answers, so a client that has its
+// response can rely on the request being recorded.
+fn handle(stream: TcpStream, response: &str, recorded: &Mutex<Vec<String>>) {
+    let mut reader: BufReader<TcpStream> = BufReader::new(stream);
+    let mut request: String = String::new();
+    let mut content_length: usize = 0;
+    loop {
+        let mut line: String = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+        request.push_str(&line);
+        if line == "\r\n" {
+            break;
+        }
+    }
+    let mut body: Vec<u8> = vec![0; content_length];
+    let _ = reader.read_exact(&mut body);
+    request.push_str(&String::from_utf8_lossy(&body));
+
+    recorded.lock().unwrap().push(request);
+    let _ = reader.get_mut().write_all(response.as_bytes());
+}
+
+impl StubServer {
+    // Each response is a complete raw HTTP response.
+    pub fn start(responses: Vec<String>) -> StubServer {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url: String = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded: Arc<Mutex<Vec<String>>> = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (i, stream) in listener.incoming().enumerate() {
+                let Ok(stream) = stream else { break };
+                let response: &String = &responses[i.min(responses.len() - 1)];
+                handle(stream, response, &recorded);
+            }
+        });
+
+        StubServer { base_url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+pub fn response(status: u16, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
@@ -0,0 +1,92 @@
+use super::stub::{StubServer, response};
+use super::{HttpError, Response, form_urlencode, get, post_form, read_response};
+
+#[test]
+fn form_values_are_percent_encoded() {
+    assert_eq!(
+        form_urlencode(&[("level", "2"), ("answer", "a b&c=d/é")]),
+        "level=2&answer=a+b%26c%3Dd%2F%C3%A9"
+    );
+}
+
+#[test]
+fn reads_content_length_bodies() {
+    let raw: &str = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello, and more";
+    assert_eq!(
+        read_response(raw.as_bytes()).unwrap(),
+        Response {
+            status: 200,
+            body: "hello".to_string()
+        }
+    );
+}
+
+#[test]
+fn reads_chunked_bodies() {
+    let raw: &str = "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n6;ext=1\r\npedia!\r\n0\r\n\r\n";
+    assert_eq!(
+        read_response(raw.as_bytes()).unwrap(),
+        Response {
+            status: 404,
+            body: "Wikipedia!".to_string()
+        }
+    );
+}
+
+#[test]
+fn reads_until_close_without_length() {
+    let raw: &str = "HTTP/1.0 200 OK\r\n\r\nall of it\n";
+    assert_eq!(read_response(raw.as_bytes()).unwrap().body, "all of it\n");
+}
+
+#[test]
+fn rejects_malformed_responses() {
+    assert!(matches!(
+        read_response("garbage\r\n".as_bytes()),
+        Err(HttpError::Malformed(_))
+    ));
+    assert!(matches!(
+        read_response("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n".as_bytes()),
+        Err(HttpError::Malformed(_))
+    ));
+}
+
+#[test]
+fn talks_to_a_plain_http_server() {
+    let server: StubServer =
+        StubServer::start(vec![response(200, "first"), response(302, "second")]);
+
+    let first: Response = get(
+        &format!("{}/2025/day/1/input", server.base_url),
+        &[("Cookie", "session=abc")],
+    )
+    .unwrap();
+    assert_eq!((first.status, first.body.as_str()), (200, "first"));
+
+    let second: Response = post_form(
+        &format!("{}/2025/day/1/answer", server.base_url),
+        &[],
+        &[("level", "1"), ("answer", "42")],
+    )
+    .unwrap();
+    assert_eq!((second.status, second.body.as_str()), (302, "second"));
+
+    let requests: Vec<String> = server.requests();
+    assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+    assert!(requests[0].contains("\r\nUser-Agent: "));
+    assert!(requests[1].starts_with("POST /2025/day/1/answer HTTP/1.1\r\n"));
+    assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=42"));
+}
+
+#[test]
+fn rejects_unknown_schemes() {
+    assert!(matches!(
+        get("ftp://example.com/", &[]),
+        Err(HttpError::InvalidUrl(_))
+    ));
+    assert!(matches!(
+        get("http:///path", &[]),
+        Err(HttpError::InvalidUrl(_))
+    ));
+}
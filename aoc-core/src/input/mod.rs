@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests;
+
+use crate::config::{CONFIG_FILE, Config};
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Overrides every other inputs directory when set.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+// The inputs directory used when nothing else is configured. It is git-ignored, so
+// personal inputs can live there instead of next to the solutions.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+// Finds puzzle input files without depending on the current directory. Inputs are
+// looked up, in order:
+//   <input dir>/<year>/day<NN>.in (or .txt)
+//   <workspace>/year<year>/src/day<NN>/input.txt (or .in)
+// The input dir is `AOC_INPUT_DIR` if set, else `dir` in the `[inputs]` section of
+// `aoc.toml`, else `<workspace>/inputs`. Relative dirs are taken from the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputLocator {
+    workspace: PathBuf,
+    input_dir: PathBuf,
+    // Whether the input dir was chosen explicitly, in which case new inputs go there.
+    configured: bool,
+}
+
+// The workspace root: the closest directory at or above `start` whose Cargo.toml
+// declares a `[workspace]`.
+pub fn find_workspace(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir: &&Path| {
+            std::fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest: String| {
+                manifest.lines().any(|l: &str| l.trim() == "[workspace]")
+            })
+        })
+        .map(Path::to_path_buf)
+}
+
+impl InputLocator {
+    // A locator for `workspace` that only knows the default locations.
+    pub fn new(workspace: impl Into<PathBuf>) -> Self {
+        let workspace: PathBuf = workspace.into();
+        InputLocator {
+            input_dir: workspace.join(DEFAULT_INPUT_DIR),
+            workspace,
+            configured: false,
+        }
+    }
+
+    pub fn with_input_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = self.workspace.join(dir.into());
+        self.configured = true;
+        self
+    }
+
+    // Applies the env var and the config file on top of the defaults. Split out of
+    // `from_env` so it can be tested without touching the process environment.
+    pub fn resolve(
+        workspace: impl Into<PathBuf>,
+        env_dir: Option<PathBuf>,
+        config: &Config,
+    ) -> Self {
+        let locator: InputLocator = InputLocator::new(workspace);
+        match (env_dir, config.get("inputs", "dir")) {
+            (Some(dir), _) => locator.with_input_dir(dir),
+            (None, Some(dir)) => locator.with_input_dir(dir),
+            (None, None) => locator,
+        }
+    }
+
+    // The locator for this workspace. The workspace is found from `CARGO_MANIFEST_DIR`
+    // (set by `cargo run` and `cargo test`), falling back to where aoc-core was built.
+    pub fn from_env() -> io::Result<Self> {
+        let start: PathBuf = env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+        let workspace: PathBuf = find_workspace(&start)
+            .or_else(|| find_workspace(Path::new(env!("CARGO_MANIFEST_DIR"))))
+            .unwrap_or(start);
+
+        let config: Config = Config::load(&workspace.join(CONFIG_FILE))?;
+        let env_dir: Option<PathBuf> = env::var_os(INPUT_DIR_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        Ok(InputLocator::resolve(workspace, env_dir, &config))
+    }
+
+    pub fn workspace(&self) -> &Path {
+        &self.workspace
+    }
+
+    pub fn input_dir(&self) -> &Path {
+        &self.input_dir
+    }
+
+    fn in_input_dir(&self, year: u16, day: u8, ext: &str) -> PathBuf {
+        self.input_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.{}", day, ext))
+    }
+
+    // The directory of a day's solution, e.g. `<workspace>/year2025/src/day05`.
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.workspace
+            .join(format!("year{}", year))
+            .join("src")
+            .join(format!("day{:02}", day))
+    }
+
+    fn in_tree(&self, year: u16, day: u8, ext: &str) -> PathBuf {
+        self.day_dir(year, day).join(format!("input.{}", ext))
+    }
+
+    // Every path an input may be read from, in the order they are tried.
+    pub fn candidates(&self, year: u16, day: u8) -> Vec<PathBuf> {
+        vec![
+            self.in_input_dir(year, day, "in"),
+            self.in_input_dir(year, day, "txt"),
+            self.in_tree(year, day, "txt"),
+            self.in_tree(year, day, "in"),
+        ]
+    }
+
+    // Where a new input should be saved: the input dir if one was configured,
+    // otherwise next to the day's solution.
+    pub fn default_path(&self, year: u16, day: u8) -> PathBuf {
+        if self.configured {
+            self.in_input_dir(year, day, "in")
+        } else {
+            self.in_tree(year, day, "in")
+        }
+    }
+
+    // Where a per-year file such as the answers ledger belongs: with the inputs if an
+    // input dir was configured, otherwise in the year crate next to the inputs there.
+    pub fn year_file(&self, year: u16, name: &str) -> PathBuf {
+        if self.configured {
+            self.input_dir.join(year.to_string()).join(name)
+        } else {
+            self.workspace.join(format!("year{}", year)).join(name)
+        }
+    }
+
+    pub fn find(&self, year: u16, day: u8) -> Option<PathBuf> {
+        self.candidates(year, day)
+            .into_iter()
+            .find(|p: &PathBuf| p.is_file())
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        match self.find(year, day) {
+            Some(path) => std::fs::read_to_string(path),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Could not find input file. Checked: {:?}",
+                    self.candidates(year, day)
+                ),
+            )),
+        }
+    }
+}
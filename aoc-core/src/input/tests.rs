@@ -0,0 +1,115 @@
+use super::{InputLocator, find_workspace};
+use crate::config::Config;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+// A fresh fake workspace per test, so tests can run in parallel without sharing files.
+fn workspace(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("aoc_core_input_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("year2025/src/day01")).unwrap();
+    fs::write(dir.join("Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+    dir
+}
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn reads_inputs_next_to_the_solution() {
+    let root: PathBuf = workspace("in_tree");
+    write(&root.join("year2025/src/day01/input.txt"), "L1\nR2\nL3\n");
+
+    let locator: InputLocator = InputLocator::new(&root);
+    assert_eq!(locator.read(2025, 1).unwrap(), "L1\nR2\nL3\n");
+    assert_eq!(
+        locator.find(2025, 1),
+        Some(root.join("year2025/src/day01/input.txt"))
+    );
+    assert_eq!(
+        locator.default_path(2025, 1),
+        root.join("year2025/src/day01/input.in")
+    );
+    assert_eq!(
+        locator.year_file(2025, "answers.toml"),
+        root.join("year2025/answers.toml")
+    );
+}
+
+#[test]
+fn inputs_dir_wins_over_the_source_tree() {
+    let root: PathBuf = workspace("inputs_dir");
+    write(&root.join("year2025/src/day01/input.in"), "tree");
+    write(&root.join("inputs/2025/day01.in"), "private");
+
+    let locator: InputLocator = InputLocator::new(&root);
+    assert_eq!(locator.read(2025, 1).unwrap(), "private");
+}
+
+#[test]
+fn configured_dir_is_relative_to_the_workspace() {
+    let root: PathBuf = workspace("configured");
+    write(
+        &root.join("../aoc_core_input_private/2025/day07.txt"),
+        "from config",
+    );
+
+    let config: Config = Config::parse("[inputs]\ndir = \"../aoc_core_input_private\"\n").unwrap();
+    let locator: InputLocator = InputLocator::resolve(&root, None, &config);
+    assert_eq!(locator.input_dir(), root.join("../aoc_core_input_private"));
+    assert_eq!(locator.read(2025, 7).unwrap(), "from config");
+    // New inputs go to the configured dir rather than into the source tree
+    assert_eq!(
+        locator.default_path(2025, 8),
+        root.join("../aoc_core_input_private/2025/day08.in")
+    );
+    assert_eq!(
+        locator.year_file(2025, "answers.toml"),
+        root.join("../aoc_core_input_private/2025/answers.toml")
+    );
+}
+
+#[test]
+fn env_dir_wins_over_config() {
+    let root: PathBuf = workspace("env");
+    let env_dir: PathBuf = root.join("from-env");
+
+    let config: Config = Config::parse("[inputs]\ndir = \"from-config\"\n").unwrap();
+    let locator: InputLocator = InputLocator::resolve(&root, Some(env_dir.clone()), &config);
+    assert_eq!(locator.input_dir(), env_dir);
+
+    let locator: InputLocator = InputLocator::resolve(&root, None, &Config::default());
+    assert_eq!(locator.input_dir(), root.join("inputs"));
+}
+
+#[test]
+fn missing_input_lists_every_candidate() {
+    let root: PathBuf = workspace("missing");
+    let locator: InputLocator = InputLocator::new(&root);
+
+    let err = locator.read(2025, 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    for candidate in locator.candidates(2025, 2) {
+        assert!(err.to_string().contains(&*candidate.to_string_lossy()));
+    }
+}
+
+#[test]
+fn workspace_is_found_from_a_member_directory() {
+    let root: PathBuf = workspace("find");
+    write(
+        &root.join("year2025/Cargo.toml"),
+        "[package]\nname = \"year2025\"\n",
+    );
+
+    assert_eq!(
+        find_workspace(&root.join("year2025/src/day01")),
+        Some(root.clone())
+    );
+    assert_eq!(find_workspace(&root), Some(root));
+}
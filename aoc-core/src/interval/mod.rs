@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod tests;
+
+use crate::math::Integer;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+// The inclusive bounds of a range, `None` if it is empty. Excluded bounds at the type's
+// limits (`..MIN`, `MAX..`) leave nothing.
+fn inclusive<T: Integer>(range: &impl RangeBounds<T>) -> Option<(T, T)> {
+    let lo: T = match range.start_bound() {
+        Bound::Included(&lo) => lo,
+        Bound::Excluded(&lo) => lo.checked_next()?,
+        Bound::Unbounded => T::MIN,
+    };
+    let hi: T = match range.end_bound() {
+        Bound::Included(&hi) => hi,
+        Bound::Excluded(&hi) => hi.checked_prev()?,
+        Bound::Unbounded => T::MAX,
+    };
+    (lo <= hi).then_some((lo, hi))
+}
+
+// A set of integers kept as sorted, disjoint intervals. Overlapping and touching
+// intervals are merged, so `1..=3` and `4..=6` become `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // Inclusive bounds, so intervals can reach `T::MAX`. Between two neighbours there is
+    // always at least one integer that is not in the set.
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    // Adds every integer of `range`, e.g. `3..=5`, `3..6` or `3..`.
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let Some((mut lo, mut hi)) = inclusive(&range) else {
+            return;
+        };
+
+        // The intervals from `first` up to `last` overlap or touch the new one.
+        let ends_before = |&(_, end): &(T, T)| end.checked_next().is_some_and(|n: T| n < lo);
+        let first: usize = self.intervals.partition_point(ends_before);
+        let last: usize = match hi.checked_next() {
+            Some(next) => self
+                .intervals
+                .partition_point(|&(start, _): &(T, T)| start <= next),
+            None => self.intervals.len(),
+        };
+
+        if first < last {
+            lo = lo.min(self.intervals[first].0);
+            hi = hi.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(lo, hi)]);
+    }
+
+    // Takes out every integer of `range`, splitting the intervals it cuts through.
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let Some((lo, hi)) = inclusive(&range) else {
+            return;
+        };
+
+        let first: usize = self
+            .intervals
+            .partition_point(|&(_, end): &(T, T)| end < lo);
+        let last: usize = self
+            .intervals
+            .partition_point(|&(start, _): &(T, T)| start <= hi);
+        if first >= last {
+            return;
+        }
+
+        let mut rest: Vec<(T, T)> = Vec::with_capacity(2);
+        let (start, _) = self.intervals[first];
+        if let Some(before) = lo.checked_prev()
+            && start <= before
+        {
+            rest.push((start, before));
+        }
+        let (_, end) = self.intervals[last - 1];
+        if let Some(after) = hi.checked_next()
+            && after <= end
+        {
+            rest.push((after, end));
+        }
+        self.intervals.splice(first..last, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union: IntervalSet<T> = self.clone();
+        for &(lo, hi) in &other.intervals {
+            union.insert(lo..=hi);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals: Vec<(T, T)> = Vec::new();
+        let (mut i, mut j): (usize, usize) = (0, 0);
+
+        while let (Some(&(a_lo, a_hi)), Some(&(b_lo, b_hi))) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let lo: T = a_lo.max(b_lo);
+            let hi: T = a_hi.min(b_hi);
+            if lo <= hi {
+                intervals.push((lo, hi));
+            }
+            // Whichever ends first cannot overlap anything further along.
+            if a_hi < b_hi {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    // The integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference: IntervalSet<T> = self.clone();
+        for &(lo, hi) in &other.intervals {
+            difference.remove(lo..=hi);
+        }
+        difference
+    }
+
+    // Everything of `T` that is not in the set.
+    pub fn complement(&self) -> IntervalSet<T> {
+        let mut all: IntervalSet<T> = IntervalSet::new();
+        all.insert(..);
+        all.difference(self)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i: usize = self
+            .intervals
+            .partition_point(|&(_, end): &(T, T)| end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|&(start, _): &(T, T)| start <= value)
+    }
+
+    // Whether every integer of `range` is in the set; true for an empty range.
+    pub fn contains_range(&self, range: impl RangeBounds<T>) -> bool {
+        let Some((lo, hi)) = inclusive(&range) else {
+            return true;
+        };
+        let i: usize = self
+            .intervals
+            .partition_point(|&(_, end): &(T, T)| end < lo);
+        self.intervals
+            .get(i)
+            .is_some_and(|&(start, end): &(T, T)| start <= lo && hi <= end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // The number of integers in the set, `None` only if that is all 2^128 of a 128-bit type.
+    pub fn checked_len(&self) -> Option<u128> {
+        self.intervals
+            .iter()
+            .try_fold(0u128, |total: u128, &(lo, hi): &(T, T)| {
+                total.checked_add(T::distance(lo, hi))?.checked_add(1)
+            })
+    }
+
+    // The number of integers in the set. Panics where `checked_len` gives up.
+    pub fn len(&self) -> u128 {
+        match self.checked_len() {
+            Some(len) => len,
+            None => panic!("every integer of a 128-bit type is more than a u128 can count"),
+        }
+    }
+
+    // The disjoint intervals, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(lo, hi): &(T, T)| lo..=hi)
+    }
+
+    // The stretches between consecutive intervals, in ascending order. Use `complement`
+    // for the parts below the first and above the last interval as well.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.windows(2).filter_map(|pair: &[(T, T)]| {
+            Some(pair[0].1.checked_next()?..=pair[1].0.checked_prev()?)
+        })
+    }
+}
+
+impl<T: Integer, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> Self {
+        let mut set: IntervalSet<T> = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
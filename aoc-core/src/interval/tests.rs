@@ -0,0 +1,106 @@
+use super::IntervalSet;
+use crate::math::Integer;
+use std::ops::RangeInclusive;
+
+fn intervals<T: Integer>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+    set.iter().collect()
+}
+
+#[test]
+fn inserting_merges_overlapping_and_touching_intervals() {
+    let mut set: IntervalSet<u32> = IntervalSet::new();
+    set.insert(10..=14);
+    set.insert(3..=5);
+    set.insert(16..20);
+    assert_eq!(intervals(&set), vec![3..=5, 10..=14, 16..=19]);
+
+    set.insert(12..=16);
+    assert_eq!(intervals(&set), vec![3..=5, 10..=19]);
+    set.insert(6..=9);
+    assert_eq!(intervals(&set), vec![3..=19]);
+    set.insert(7..7);
+    assert_eq!(intervals(&set), vec![3..=19]);
+
+    assert_eq!(set.len(), 17);
+    assert!(set.contains(3) && set.contains(19));
+    assert!(!set.contains(2) && !set.contains(20));
+}
+
+#[test]
+fn removing_splits_intervals() {
+    let mut set: IntervalSet<i64> = [-10..=10, 20..=30].into_iter().collect();
+    set.remove(-2..3);
+    set.remove(25..);
+    assert_eq!(intervals(&set), vec![-10..=-3, 3..=10, 20..=24]);
+
+    set.remove(0..=22);
+    assert_eq!(intervals(&set), vec![-10..=-3, 23..=24]);
+    set.remove(..);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn combines_sets() {
+    let a: IntervalSet<u8> = [1..=5, 10..=15].into_iter().collect();
+    let b: IntervalSet<u8> = [4..=11, 20..=22].into_iter().collect();
+
+    assert_eq!(intervals(&a.union(&b)), vec![1..=15, 20..=22]);
+    assert_eq!(intervals(&a.intersection(&b)), vec![4..=5, 10..=11]);
+    assert_eq!(intervals(&a.difference(&b)), vec![1..=3, 12..=15]);
+    assert_eq!(intervals(&b.difference(&a)), vec![6..=9, 20..=22]);
+    assert!(a.intersection(&IntervalSet::new()).is_empty());
+}
+
+#[test]
+fn answers_containment_queries() {
+    let set: IntervalSet<u128> = [3..=5, 10..=14].into_iter().collect();
+
+    assert!(set.contains_range(10..=14));
+    assert!(set.contains_range(4..6));
+    assert!(!set.contains_range(4..=10));
+    assert!(!set.contains_range(14..=15));
+    assert!(set.contains_range(20..20));
+    assert!(!IntervalSet::<u128>::new().contains(0));
+}
+
+#[test]
+fn iterates_over_gaps() {
+    let set: IntervalSet<i32> = [-5..=-1, 1..=2, 8..=9].into_iter().collect();
+
+    assert_eq!(set.gaps().collect::<Vec<_>>(), vec![0..=0, 3..=7]);
+    assert_eq!(
+        intervals(&set.complement()),
+        vec![i32::MIN..=-6, 0..=0, 3..=7, 10..=i32::MAX]
+    );
+}
+
+#[test]
+fn handles_the_limits_of_the_integer_type() {
+    let mut set: IntervalSet<u8> = IntervalSet::new();
+    set.insert(250..=u8::MAX);
+    set.insert(u8::MAX..);
+    set.insert(..0);
+    set.insert(u8::MAX..u8::MAX);
+    assert_eq!(intervals(&set), vec![250..=255]);
+    assert_eq!(set.len(), 6);
+
+    set.insert(0..=249);
+    assert_eq!(intervals(&set), vec![0..=255]);
+    assert_eq!(set.len(), 256);
+    assert!(set.complement().is_empty());
+    set.remove(..=0);
+    set.remove(u8::MAX..);
+    assert_eq!(intervals(&set), vec![1..=254]);
+
+    let signed: IntervalSet<i128> = [i128::MIN..=i128::MIN + 1, i128::MAX..=i128::MAX]
+        .into_iter()
+        .collect();
+    assert_eq!(signed.len(), 3);
+    assert!(signed.contains(i128::MIN) && signed.contains(i128::MAX));
+
+    let mut all: IntervalSet<i128> = IntervalSet::new();
+    all.insert(..);
+    assert_eq!(all.checked_len(), None);
+    all.remove(0..1);
+    assert_eq!(all.len(), u128::MAX);
+}
@@ -1,41 +1,105 @@
 #[cfg(test)]
 mod tests;
 
-pub trait Solver {
-    fn solve(&self, input: &str) -> Vec<String>;
+mod answer;
+pub mod answers;
+pub mod collections;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod puzzle;
+pub mod registry;
+pub mod submit;
+
+pub use answer::Answer;
+pub use error::SolveError;
+pub use examples::check_examples;
+pub use input::InputLocator;
+pub use registry::{Day, Registry};
+
+// A puzzle solution. The input is parsed once and the result is shared by both parts,
+// so the parse cost is paid (and can be timed) separately. Solvers must be shareable
+// between threads, as the runner solves several days at once.
+pub trait Solver: Send + Sync {
+    type Parsed;
+
+    // Whether `parse` does any work of its own. Solvers that leave everything to the
+    // parts turn this off, so that no parse phase is reported or timed for them.
+    const PARSES: bool = true;
+
+    // Whether any part is solved yet. Stubs turn this off, so that runs over a whole
+    // year skip them while they have no input.
+    const IMPLEMENTED: bool = true;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError>;
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    fn solve(&self, input: &str) -> Result<[Answer; 2], SolveError> {
+        let parsed: Self::Parsed = self.parse(input)?;
+        Ok([self.part1(&parsed)?, self.part2(&parsed)?])
+    }
 }
 
-pub struct Day {
-    pub name: &'static str,
-    pub solver: Box<dyn Solver>,
+// Object-safe view of a `Solver`, used wherever solvers of different days are mixed,
+// e.g. by the runner. Every `Solver` gets it for free.
+pub trait DynSolver: Send + Sync {
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, SolveError>;
+
+    // See `Solver::PARSES`.
+    fn parses(&self) -> bool;
+
+    // See `Solver::IMPLEMENTED`.
+    fn implemented(&self) -> bool;
 }
 
-pub fn read_input(year: u16, day: u8) -> Result<String, std::io::Error> {
-    let base_paths: [String; 3] = [
-        format!("year{}/src/day{:02}/input", year, day), // From workspace root
-        format!("src/day{:02}/input", day),              // From year crate root
-        format!("day{:02}/input", day),                  // From src dir (unlikely but possible)
-    ];
-
-    let extensions: [&str; 2] = ["txt", "in"];
-
-    for base in &base_paths {
-        for ext in &extensions {
-            let path: String = format!("{base}.{ext}");
-            if let Ok(content) = std::fs::read_to_string(path) {
-                return Ok(content);
-            }
-        }
+// Parsed input bound to the solver that produced it.
+pub trait Prepared {
+    fn part1(&self) -> Result<Answer, SolveError>;
+    fn part2(&self) -> Result<Answer, SolveError>;
+}
+
+struct PreparedInput<'a, S: Solver> {
+    solver: &'a S,
+    parsed: S::Parsed,
+}
+
+impl<S: Solver> Prepared for PreparedInput<'_, S> {
+    fn part1(&self) -> Result<Answer, SolveError> {
+        self.solver.part1(&self.parsed)
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        self.solver.part2(&self.parsed)
+    }
+}
+
+impl<S: Solver> DynSolver for S {
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, SolveError> {
+        let parsed: S::Parsed = self.parse(input)?;
+        Ok(Box::new(PreparedInput {
+            solver: self,
+            parsed,
+        }))
     }
 
-    Err(std::io::Error::new(
-        std::io::ErrorKind::NotFound,
-        format!(
-            "Could not find input file. Checked: {:?}",
-            base_paths
-                .iter()
-                .flat_map(|b: &String| extensions.iter().map(move |e: &&str| format!("{b}.{e}")))
-                .collect::<Vec<_>>()
-        ),
-    ))
+    fn parses(&self) -> bool {
+        S::PARSES
+    }
+
+    fn implemented(&self) -> bool {
+        S::IMPLEMENTED
+    }
+}
+
+// Reads the input of one day, see `InputLocator` for where it is looked for.
+pub fn read_input(year: u16, day: u8) -> Result<String, std::io::Error> {
+    InputLocator::from_env()?.read(year, day)
 }
@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+// A primitive integer type.
+pub trait Integer:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    // `hi - lo` for `lo <= hi`, which always fits in a `u128`.
+    fn distance(lo: Self, hi: Self) -> u128;
+
+    fn checked_next(self) -> Option<Self> {
+        self.checked_add(Self::ONE)
+    }
+
+    fn checked_prev(self) -> Option<Self> {
+        self.checked_sub(Self::ONE)
+    }
+}
+
+// An integer type with negative numbers.
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                // Two's complement makes the wrapped difference the exact one, also for
+                // signed types.
+                fn distance(lo: Self, hi: Self) -> u128 {
+                    (hi as u128).wrapping_sub(lo as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+fn abs<T: Integer>(n: T) -> T {
+    if n < T::ZERO { T::ZERO - n } else { n }
+}
+
+// Never negative; `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    abs(a)
+}
+
+// Never negative; 0 if either is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r): (T, T) = (a, b);
+    let (mut old_x, mut x): (T, T) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y): (T, T) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q: T = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (T::ZERO - old_r, T::ZERO - old_x, T::ZERO - old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// `a * b % m` without overflowing, however large the operands.
+pub fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b): (u128, u128) = (a % m, b % m);
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Double and add, keeping every intermediate below `m`.
+    let mut product: u128 = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    product
+}
+
+// `(a + b) % m` for `a, b < m`.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+pub fn pow_mod(base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut base: u128 = base % m;
+    let mut result: u128 = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// The `x < m` with `a * x % m == 1`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: u128, m: u128) -> Option<u128> {
+    if m == 0 {
+        return None;
+    }
+
+    // Extended Euclid, keeping only the coefficients of `a`, reduced modulo `m`.
+    let (mut old_r, mut r): (u128, u128) = (a % m, m);
+    let (mut old_s, mut s): (u128, u128) = (1 % m, 0);
+    while r != 0 {
+        let q: u128 = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        let qs: u128 = mul_mod(q, s, m);
+        (old_s, s) = (
+            s,
+            if old_s >= qs {
+                old_s - qs
+            } else {
+                m - (qs - old_s)
+            },
+        );
+    }
+
+    (old_r == 1).then_some(old_s)
+}
+
+// Solves `x % m == r` for every `(r, m)` at once, giving `(x, lcm of all m)` with the
+// smallest such `x`. The moduli need not be coprime; `None` if the congruences
+// contradict each other. Panics if the lcm does not fit in a `u128`.
+pub fn crt(congruences: &[(u128, u128)]) -> Option<(u128, u128)> {
+    let mut x: u128 = 0;
+    let mut modulus: u128 = 1;
+
+    for &(r, m) in congruences {
+        assert!(m > 0, "a congruence modulo 0");
+        let r: u128 = r % m;
+        let g: u128 = gcd(modulus, m);
+        let diff: u128 = add_mod(r, (m - x % m) % m, m);
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+
+        // x + modulus * t satisfies both when modulus / g * t == diff / g (mod m / g).
+        let step: u128 = m / g;
+        let t: u128 = mul_mod(diff / g, mod_inverse(modulus / g, step)?, step);
+        let combined: u128 = match (modulus / g).checked_mul(m) {
+            Some(combined) => combined,
+            None => panic!("the lcm of the moduli does not fit in a u128"),
+        };
+        x = add_mod(x, modulus * t, combined);
+        modulus = combined;
+    }
+
+    Some((x, modulus))
+}
+
+// The primes up to and including `n`, by the sieve of Eratosthenes.
+pub fn primes_up_to(n: usize) -> Vec<usize> {
+    let mut composite: Vec<bool> = vec![false; n + 1];
+    let mut primes: Vec<usize> = Vec::new();
+
+    for i in 2..=n {
+        if composite[i] {
+            continue;
+        }
+        primes.push(i);
+        for multiple in (i.saturating_mul(i)..=n).step_by(i) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
+
+// The prime factors of `n` with their exponents, smallest first. 1 has none.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "0 has no factorization");
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    let mut d: u64 = 2;
+
+    while d.saturating_mul(d) <= n {
+        let mut exponent: u32 = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((d, exponent));
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+// Every divisor of `n`, in ascending order.
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors: Vec<u64> = vec![1];
+    for (p, exponent) in factorize(n) {
+        let smaller: usize = divisors.len();
+        let mut power: u64 = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..smaller {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+// The number of decimal digits; 0 has one.
+pub fn digit_count(n: u128) -> u32 {
+    n.checked_ilog10().map_or(1, |log: u32| log + 1)
+}
+
+// The decimal digits, most significant first.
+pub fn digits(mut n: u128) -> Vec<u8> {
+    let mut digits: Vec<u8> = vec![(n % 10) as u8];
+    n /= 10;
+    while n > 0 {
+        digits.push((n % 10) as u8);
+        n /= 10;
+    }
+    digits.reverse();
+    digits
+}
+
+// The number a block of `block_len` digits is multiplied by to repeat it `repeats`
+// times: 1 + 10^L + 10^(2L) + ..., e.g. 10101 turns 12 into 121212. `None` if it
+// does not fit in a `u128`.
+pub fn repeat_multiplier(block_len: u32, repeats: u32) -> Option<u128> {
+    let step: u128 = 10u128.checked_pow(block_len)?;
+    (0..repeats).try_fold(0u128, |multiplier: u128, _| {
+        multiplier.checked_mul(step)?.checked_add(1)
+    })
+}
+
+// `block` written `repeats` times in a row, padded to `block_len` digits each time, e.g.
+// `repeat_block(7, 2, 3)` is 070707, which is 70707.
+pub fn repeat_block(block: u128, block_len: u32, repeats: u32) -> Option<u128> {
+    block.checked_mul(repeat_multiplier(block_len, repeats)?)
+}
+
+// A number whose digits are all `digit`, e.g. `repdigit(7, 3)` is 777.
+pub fn repdigit(digit: u8, len: u32) -> Option<u128> {
+    repeat_block(u128::from(digit), 1, len)
+}
+
+// Whether the digits of `n` are one block repeated exactly `repeats` times.
+pub fn is_repeated_block(n: u128, repeats: u32) -> bool {
+    let len: u32 = digit_count(n);
+    if repeats == 0 || !len.is_multiple_of(repeats) {
+        return false;
+    }
+    repeat_multiplier(len / repeats, repeats).is_some_and(|m: u128| n.is_multiple_of(m))
+}
+
+// `first + (first + step) + ...` over `count` terms.
+pub fn ap_sum(first: u128, step: u128, count: u128) -> u128 {
+    if count == 0 {
+        return 0;
+    }
+    first * count + step * triangle(count - 1)
+}
+
+// `lo + (lo + 1) + ... + hi`, 0 if `lo > hi`.
+pub fn range_sum(lo: u128, hi: u128) -> u128 {
+    if lo > hi {
+        return 0;
+    }
+    // Halve whichever factor is even before multiplying.
+    let (sum, count): (u128, u128) = (lo + hi, hi - lo + 1);
+    if sum.is_multiple_of(2) {
+        sum / 2 * count
+    } else {
+        count / 2 * sum
+    }
+}
+
+// 0 + 1 + ... + n.
+fn triangle(n: u128) -> u128 {
+    range_sum(0, n)
+}
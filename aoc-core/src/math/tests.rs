@@ -0,0 +1,227 @@
+use super::{
+    ap_sum, crt, digit_count, digits, divisors, extended_gcd, factorize, gcd, is_repeated_block,
+    lcm, mod_inverse, mul_mod, pow_mod, primes_up_to, range_sum, repdigit, repeat_block,
+    repeat_multiplier,
+};
+
+// A small xorshift generator, so the property tests below see the same "random" cases
+// on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+const CASES: usize = 2_000;
+
+fn brute_gcd(a: u64, b: u64) -> u64 {
+    (1..=a.max(b))
+        .rev()
+        .find(|&d: &u64| a.is_multiple_of(d) && b.is_multiple_of(d))
+        .unwrap_or(0)
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..n).all(|d: u64| !n.is_multiple_of(d))
+}
+
+#[test]
+fn gcd_and_lcm_match_brute_force() {
+    let mut rng: Rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..CASES {
+        let (a, b): (u64, u64) = (rng.below(300), rng.below(300));
+        let g: u64 = gcd(a, b);
+        assert_eq!(g, brute_gcd(a, b), "gcd({a}, {b})");
+        assert_eq!(gcd(a as i64, -(b as i64)), g as i64);
+
+        let l: u64 = lcm(a, b);
+        let brute: u64 = if a == 0 || b == 0 {
+            0
+        } else {
+            (1..=a * b)
+                .find(|&m: &u64| m.is_multiple_of(a) && m.is_multiple_of(b))
+                .unwrap()
+        };
+        assert_eq!(l, brute, "lcm({a}, {b})");
+        assert_eq!(lcm(-(a as i32), b as i32), l as i32);
+    }
+    assert_eq!(gcd(0u8, 0), 0);
+}
+
+#[test]
+fn extended_gcd_gives_bezout_coefficients() {
+    let mut rng: Rng = Rng(12345);
+    for _ in 0..CASES {
+        let a: i64 = rng.below(2_000_001) as i64 - 1_000_000;
+        let b: i64 = rng.below(2_000_001) as i64 - 1_000_000;
+        let (g, x, y): (i64, i64, i64) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b), "extended_gcd({a}, {b})");
+        assert_eq!(a * x + b * y, g, "extended_gcd({a}, {b})");
+    }
+}
+
+#[test]
+fn modular_arithmetic_matches_brute_force() {
+    let mut rng: Rng = Rng(777);
+    for _ in 0..CASES {
+        let m: u128 = u128::from(rng.below(500)) + 1;
+        let a: u128 = u128::from(rng.next());
+        let e: u128 = u128::from(rng.below(40));
+
+        let brute_pow: u128 = (0..e).fold(1 % m, |acc: u128, _| acc * (a % m) % m);
+        assert_eq!(pow_mod(a, e, m), brute_pow, "{a}^{e} mod {m}");
+
+        let brute_inverse: Option<u128> = (0..m).find(|&x: &u128| a % m * x % m == 1 % m);
+        let inverse: Option<u128> = mod_inverse(a, m);
+        assert_eq!(inverse.is_some(), gcd(a, m) == 1, "inverse of {a} mod {m}");
+        assert_eq!(inverse, brute_inverse.filter(|_| gcd(a, m) == 1));
+    }
+    assert_eq!(mod_inverse(3, 0), None);
+}
+
+#[test]
+fn modular_arithmetic_works_near_the_limits() {
+    let m: u128 = u128::MAX - 158; // 2^128 - 159 is prime.
+    assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+    assert_eq!(mul_mod(u128::MAX, 2, m), 316);
+    // Fermat: a^(m-1) == 1 for a prime m.
+    assert_eq!(pow_mod(123_456_789, m - 1, m), 1);
+    let inverse: u128 = mod_inverse(123_456_789, m).unwrap();
+    assert_eq!(mul_mod(inverse, 123_456_789, m), 1);
+}
+
+#[test]
+fn crt_matches_brute_force() {
+    let mut rng: Rng = Rng(4242);
+    for _ in 0..CASES {
+        let congruences: Vec<(u128, u128)> = (0..rng.below(4))
+            .map(|_| {
+                let m: u128 = u128::from(rng.below(12)) + 1;
+                (u128::from(rng.below(30)), m)
+            })
+            .collect();
+        let modulus: u128 = congruences
+            .iter()
+            .fold(1, |l: u128, &(_, m): &(u128, u128)| lcm(l, m));
+        let brute: Option<u128> = (0..modulus).find(|&x: &u128| {
+            congruences
+                .iter()
+                .all(|&(r, m): &(u128, u128)| x % m == r % m)
+        });
+
+        assert_eq!(
+            crt(&congruences),
+            brute.map(|x: u128| (x, modulus)),
+            "{congruences:?}"
+        );
+    }
+
+    let big: u128 = 1 << 100;
+    assert_eq!(crt(&[(5, big), (1, 3)]), Some((big * 2 + 5, big * 3)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+}
+
+#[test]
+fn sieve_and_factorization_match_brute_force() {
+    let brute: Vec<usize> = (0..1_000u64)
+        .filter(|&n: &u64| is_prime(n))
+        .map(|n: u64| n as usize)
+        .collect();
+    assert_eq!(primes_up_to(999), brute);
+    assert_eq!(primes_up_to(1), vec![]);
+    assert_eq!(primes_up_to(2), vec![2]);
+
+    let mut rng: Rng = Rng(99);
+    for _ in 0..CASES {
+        let n: u64 = rng.below(5_000) + 1;
+        let factors: Vec<(u64, u32)> = factorize(n);
+        assert!(
+            factors.iter().all(|&(p, _): &(u64, u32)| is_prime(p)),
+            "{n}"
+        );
+        assert!(factors.windows(2).all(|w: &[(u64, u32)]| w[0].0 < w[1].0));
+        let product: u64 = factors
+            .iter()
+            .map(|&(p, e): &(u64, u32)| p.pow(e))
+            .product();
+        assert_eq!(product, n);
+
+        let brute: Vec<u64> = (1..=n).filter(|&d: &u64| n.is_multiple_of(d)).collect();
+        assert_eq!(divisors(n), brute, "divisors of {n}");
+    }
+    assert_eq!(factorize(1), vec![]);
+    assert_eq!(factorize(1_000_000_007), vec![(1_000_000_007, 1)]);
+    assert_eq!(factorize(1 << 40), vec![(2, 40)]);
+}
+
+#[test]
+fn digit_utilities_match_the_decimal_text() {
+    let mut rng: Rng = Rng(2025);
+    for _ in 0..CASES {
+        let n: u128 = u128::from(rng.next()) >> rng.below(64);
+        let text: String = n.to_string();
+        assert_eq!(digit_count(n), text.len() as u32, "{n}");
+        let expected: Vec<u8> = text.bytes().map(|b: u8| b - b'0').collect();
+        assert_eq!(digits(n), expected);
+
+        for repeats in 1..=4 {
+            let len: usize = text.len();
+            let brute: bool =
+                len.is_multiple_of(repeats) && text == text[..len / repeats].repeat(repeats);
+            assert_eq!(
+                is_repeated_block(n, repeats as u32),
+                brute,
+                "{n} x{repeats}"
+            );
+        }
+    }
+    assert_eq!(digit_count(0), 1);
+    assert_eq!(digit_count(u128::MAX), 39);
+    assert_eq!(digits(0), vec![0]);
+}
+
+#[test]
+fn builds_repeated_numbers() {
+    assert_eq!(repeat_multiplier(2, 3), Some(10101));
+    assert_eq!(repeat_multiplier(3, 1), Some(1));
+    assert_eq!(repeat_multiplier(3, 0), Some(0));
+    assert_eq!(repeat_multiplier(1, 39), "1".repeat(39).parse().ok());
+    assert_eq!(repeat_multiplier(1, 40), None);
+    assert_eq!(repeat_multiplier(40, 2), None);
+    assert_eq!(repeat_block(12, 2, 3), Some(121212));
+    assert_eq!(repeat_block(7, 2, 3), Some(70707));
+    assert_eq!(repdigit(7, 3), Some(777));
+    assert_eq!(repdigit(9, 39), None);
+    assert!(is_repeated_block(1212, 2));
+    assert!(!is_repeated_block(1213, 2));
+    assert!(!is_repeated_block(5, 0));
+}
+
+#[test]
+fn progression_sums_match_brute_force() {
+    let mut rng: Rng = Rng(31337);
+    for _ in 0..CASES {
+        let (first, step, count): (u128, u128, u128) = (
+            u128::from(rng.below(1_000)),
+            u128::from(rng.below(50)),
+            u128::from(rng.below(200)),
+        );
+        let brute: u128 = (0..count).map(|i: u128| first + i * step).sum();
+        assert_eq!(ap_sum(first, step, count), brute, "{first} {step} {count}");
+
+        let (lo, hi): (u128, u128) = (u128::from(rng.below(500)), u128::from(rng.below(500)));
+        assert_eq!(range_sum(lo, hi), (lo..=hi).sum::<u128>(), "{lo}..={hi}");
+    }
+
+    let hi: u128 = 1 << 63;
+    assert_eq!(range_sum(1, hi), hi / 2 * (hi + 1));
+}
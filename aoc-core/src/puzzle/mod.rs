@@ -0,0 +1,283 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fetch::{Client, FetchError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const README_FILE: &str = "README.txt";
+
+// The heading the second part of a puzzle starts with, once it is unlocked.
+pub const PART_TWO: &str = "--- Part Two ---";
+
+// What is worth keeping of a puzzle page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    // The name from the `--- Day 5: Cafeteria ---` heading.
+    pub title: Option<String>,
+    // The puzzle articles as plain text, in the layout of the README.txt files.
+    pub text: String,
+    // The contents of the `<pre><code>` blocks, without duplicates.
+    pub examples: Vec<String>,
+    pub part_two: bool,
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out: String = String::with_capacity(text.len());
+    let mut rest: &str = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let decoded: Option<(char, usize)> = rest.find(';').and_then(|end: usize| {
+            let c: char = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code: u32 = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text: String = String::with_capacity(html.len());
+    let mut in_tag: bool = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+// The inner HTML of every `<tag ...>...</tag>` element.
+fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let (open, close): (String, String) = (format!("<{}", tag), format!("</{}>", tag));
+    let mut found: Vec<&str> = Vec::new();
+    let mut rest: &str = html;
+    while let Some(start) = rest.find(&open) {
+        let Some(body) = rest[start..].find('>').map(|i: usize| start + i + 1) else {
+            break;
+        };
+        let end: usize = rest[body..]
+            .find(&close)
+            .map_or(rest.len(), |i: usize| body + i);
+        found.push(&rest[body..end]);
+        rest = &rest[(end + close.len()).min(rest.len())..];
+    }
+    found
+}
+
+// Renders an article as text: headings and list items on a line of their own,
+// paragraphs followed by a blank line, and preformatted blocks kept as they are.
+fn render(html: &str) -> String {
+    let mut out: String = String::new();
+    let mut in_pre: bool = false;
+
+    let push_text = |out: &mut String, text: &str, in_pre: bool| {
+        let text: String = decode_entities(text);
+        if in_pre {
+            out.push_str(&text);
+            return;
+        }
+        let mut collapsed: String = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                collapsed.push(c);
+            } else if !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+        }
+        if out.is_empty() || out.ends_with(['\n', ' ']) {
+            out.push_str(collapsed.trim_start());
+        } else {
+            out.push_str(&collapsed);
+        }
+    };
+    let end_line = |out: &mut String, blank: bool| {
+        out.truncate(out.trim_end_matches(' ').len());
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        if blank && !out.ends_with("\n\n") {
+            out.push('\n');
+        }
+    };
+
+    let mut rest: &str = html;
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start], in_pre);
+        let Some(end) = rest[start..].find('>').map(|i: usize| start + i) else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag: &str = &rest[start + 1..end];
+        let closing: bool = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match (name.as_str(), closing) {
+            ("pre", false) => {
+                if !out.is_empty() {
+                    end_line(&mut out, false);
+                }
+                in_pre = true;
+            }
+            ("pre", true) => {
+                in_pre = false;
+                end_line(&mut out, false);
+            }
+            ("h2" | "li" | "br", _) if closing || name == "br" => end_line(&mut out, false),
+            ("p", true) => end_line(&mut out, true),
+            _ => {}
+        }
+        rest = &rest[end + 1..];
+    }
+    push_text(&mut out, rest, in_pre);
+    out
+}
+
+// The name in a `--- Day 5: Cafeteria ---` heading.
+fn day_title(heading: &str) -> Option<String> {
+    let name: &str = heading.trim().trim_matches('-').trim();
+    let (_, name) = name.split_once(':')?;
+    Some(name.trim().to_string())
+}
+
+pub fn parse_page(html: &str) -> Puzzle {
+    let articles: Vec<&str> = elements(html, "article");
+    let text: String = articles
+        .iter()
+        .map(|a: &&str| render(a))
+        .collect::<Vec<String>>()
+        .join("");
+
+    let title: Option<String> = articles
+        .first()
+        .and_then(|a: &&str| elements(a, "h2").first().map(|h: &&str| render(h)))
+        .and_then(|heading: String| day_title(&heading));
+
+    let mut examples: Vec<String> = Vec::new();
+    for block in articles.iter().flat_map(|a: &&str| elements(a, "pre")) {
+        let example: String = match elements(block, "code").first() {
+            Some(code) => strip_tags(code),
+            None => continue,
+        };
+        if !examples.contains(&example) {
+            examples.push(example);
+        }
+    }
+
+    Puzzle {
+        title,
+        text: text.trim_end().to_string() + "\n",
+        examples,
+        part_two: articles.len() > 1,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readme {
+    // Both parts were already there, so nothing was downloaded.
+    Complete,
+    Created,
+    Updated,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Saved {
+    pub readme: Readme,
+    pub part_two: bool,
+    pub title: Option<String>,
+    // Example files written by this call; existing ones are never overwritten.
+    pub examples: Vec<PathBuf>,
+}
+
+pub fn example_path(dir: &Path, n: usize) -> PathBuf {
+    dir.join(format!("example{}.in", n))
+}
+
+// Downloads the page of a puzzle into `dir`: its text into README.txt and its example
+// blocks into example1.in, example2.in, ... Once README.txt has Part Two there is
+// nothing left to fetch. Without a session only Part One is shown, so the session is
+// sent whenever there is one.
+pub fn fetch_puzzle(client: &Client, dir: &Path, year: u16, day: u8) -> Result<Saved, FetchError> {
+    let readme: PathBuf = dir.join(README_FILE);
+    let current: Option<String> = fs::read_to_string(&readme).ok();
+    if let Some(text) = &current
+        && text.contains(PART_TWO)
+    {
+        // The README starts with the puzzle's heading, so the title is still known.
+        return Ok(Saved {
+            readme: Readme::Complete,
+            part_two: true,
+            title: text.lines().next().and_then(day_title),
+            examples: Vec::new(),
+        });
+    }
+
+    let puzzle: Puzzle = parse_page(&client.get_page(&format!("/{year}/day/{day}"))?);
+    if puzzle.text.trim().is_empty() {
+        return Err(FetchError::Empty(format!(
+            "{}/{}/day/{}",
+            client.base_url(),
+            year,
+            day
+        )));
+    }
+
+    fs::create_dir_all(dir)?;
+    let status: Readme = match current {
+        Some(text) if text == puzzle.text => Readme::Unchanged,
+        Some(_) => Readme::Updated,
+        None => Readme::Created,
+    };
+    if status != Readme::Unchanged {
+        fs::write(&readme, &puzzle.text)?;
+    }
+
+    let mut examples: Vec<PathBuf> = Vec::new();
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        let path: PathBuf = example_path(dir, i + 1);
+        if !path.exists() {
+            fs::write(&path, example)?;
+            examples.push(path);
+        }
+    }
+
+    Ok(Saved {
+        readme: status,
+        part_two: puzzle.part_two,
+        title: puzzle.title,
+        examples,
+    })
+}
@@ -0,0 +1,161 @@
+use super::{Puzzle, Readme, Saved, fetch_puzzle, parse_page};
+use crate::fetch::Client;
+use crate::http::stub::{StubServer, response};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 5: Cafeteria ---</h2><p>The Elves give you a copy of their <em>database</em> (your puzzle input).</p>
+<p>For example:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<p>The range <code>3-5</code> means that IDs 3, 4, and 5 are &quot;fresh&quot;:</p>
+<ul>
+<li>Ingredient ID <code>1</code> is spoiled.</li>
+<li>Ingredient ID <code>5</code> is <em>fresh</em>.</li>
+</ul>
+<p>So, in this example, <code><em>1</em></code> ingredient is fresh &amp; ready.</p>
+</article>
+"#;
+
+const PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Here are the ranges again:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<pre><code><em>3</em>-&gt;5
+</code></pre>
+<p>How many IDs are fresh?</p>
+</article>
+"#;
+
+fn page(articles: &[&str]) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n{}<p>Your puzzle answer was <code>674</code>.</p>\n</main></body></html>\n",
+        articles.join("<p>Your puzzle answer was <code>3</code>.</p>\n")
+    )
+}
+
+fn day_dir(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("aoc_core_puzzle_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn renders_articles_as_plain_text() {
+    let puzzle: Puzzle = parse_page(&page(&[PART_ONE]));
+
+    assert_eq!(puzzle.title.as_deref(), Some("Cafeteria"));
+    assert!(!puzzle.part_two);
+    assert_eq!(
+        puzzle.text,
+        "--- Day 5: Cafeteria ---\n\
+         The Elves give you a copy of their database (your puzzle input).\n\
+         \n\
+         For example:\n\
+         \n\
+         3-5\n10-14\n\n1\n5\n\
+         The range 3-5 means that IDs 3, 4, and 5 are \"fresh\":\n\
+         \n\
+         Ingredient ID 1 is spoiled.\n\
+         Ingredient ID 5 is fresh.\n\
+         So, in this example, 1 ingredient is fresh & ready.\n"
+    );
+    assert_eq!(puzzle.examples, vec!["3-5\n10-14\n\n1\n5\n"]);
+}
+
+#[test]
+fn includes_part_two_and_its_new_examples() {
+    let puzzle: Puzzle = parse_page(&page(&[PART_ONE, PART_TWO]));
+
+    assert!(puzzle.part_two);
+    assert!(
+        puzzle
+            .text
+            .contains("fresh & ready.\n\n--- Part Two ---\nHere are the ranges again:\n\n3-5\n")
+    );
+    assert!(puzzle.text.ends_with("3->5\nHow many IDs are fresh?\n"));
+    // The repeated block is only kept once.
+    assert_eq!(puzzle.examples, vec!["3-5\n10-14\n\n1\n5\n", "3->5\n"]);
+}
+
+#[test]
+fn pages_without_articles_are_empty() {
+    let puzzle: Puzzle = parse_page(
+        "<html><body>Please don't repeatedly request this endpoint before it unlocks!</body></html>",
+    );
+    assert_eq!(puzzle.title, None);
+    assert_eq!(puzzle.text.trim(), "");
+    assert!(puzzle.examples.is_empty());
+}
+
+#[test]
+fn saves_the_readme_and_updates_it_with_part_two() {
+    let server: StubServer = StubServer::start(vec![
+        response(200, &page(&[PART_ONE])),
+        response(200, &page(&[PART_ONE, PART_TWO])),
+    ]);
+    let client: Client = Client::new(&server.base_url);
+    let dir: PathBuf = day_dir("readme");
+
+    let saved: Saved = fetch_puzzle(&client, &dir, 2025, 5).unwrap();
+    assert_eq!(saved.readme, Readme::Created);
+    assert_eq!(saved.title.as_deref(), Some("Cafeteria"));
+    assert_eq!(saved.examples, vec![dir.join("example1.in")]);
+    assert!(
+        !fs::read_to_string(dir.join("README.txt"))
+            .unwrap()
+            .contains("Part Two")
+    );
+    // Without a session, no cookie is sent.
+    assert!(server.requests()[0].starts_with("GET /2025/day/5 HTTP/1.1"));
+    assert!(!server.requests()[0].contains("Cookie"));
+
+    // Hand edits to an example survive, new examples are added after it.
+    fs::write(dir.join("example1.in"), "edited\n").unwrap();
+    let client: Client = client.with_session("secret");
+    let saved: Saved = fetch_puzzle(&client, &dir, 2025, 5).unwrap();
+    assert_eq!(saved.readme, Readme::Updated);
+    assert!(saved.part_two);
+    assert_eq!(saved.examples, vec![dir.join("example2.in")]);
+    assert_eq!(
+        fs::read_to_string(dir.join("example1.in")).unwrap(),
+        "edited\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("example2.in")).unwrap(),
+        "3->5\n"
+    );
+    assert!(server.requests()[1].contains("Cookie: session=secret"));
+
+    // With both parts saved there is nothing left to download, but the title is
+    // still there for a day that has no name yet.
+    let saved: Saved = fetch_puzzle(&client, &dir, 2025, 5).unwrap();
+    assert_eq!(saved.readme, Readme::Complete);
+    assert_eq!(saved.title.as_deref(), Some("Cafeteria"));
+    assert_eq!(server.requests().len(), 2);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn an_unchanged_page_is_not_rewritten() {
+    let server: StubServer = StubServer::start(vec![response(200, &page(&[PART_ONE]))]);
+    let client: Client = Client::new(&server.base_url);
+    let dir: PathBuf = day_dir("unchanged");
+
+    fetch_puzzle(&client, &dir, 2025, 5).unwrap();
+    let saved: Saved = fetch_puzzle(&client, &dir, 2025, 5).unwrap();
+    assert_eq!(saved.readme, Readme::Unchanged);
+    assert!(saved.examples.is_empty());
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests;
+
+use crate::DynSolver;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub solver: &'static dyn DynSolver,
+}
+
+// Every registered day of every year. Each year crate exports its own `DAYS` table
+// (see `register_days!`); the runner lists those tables once and looks days up here.
+pub struct Registry {
+    years: &'static [&'static [Day]],
+}
+
+impl Registry {
+    pub const fn new(years: &'static [&'static [Day]]) -> Self {
+        Registry { years }
+    }
+
+    // All days, ordered by year and then by day.
+    pub fn days(&self) -> impl Iterator<Item = &'static Day> {
+        let mut days: Vec<&'static Day> = self.years.iter().flat_map(|year| year.iter()).collect();
+        days.sort_by_key(|d: &&Day| (d.year, d.day));
+        days.into_iter()
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.days().map(|d: &Day| d.year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&'static Day> {
+        self.days().find(|d: &&Day| d.year == year && d.day == day)
+    }
+}
+
+// Turns a module name like `day07` into its day number. Evaluated at compile time by
+// `register_days!`, so a misnamed module is a build error rather than a missing day.
+pub const fn day_number(module: &str) -> u8 {
+    let bytes: &[u8] = module.as_bytes();
+    if bytes.len() <= 3 || bytes[0] != b'd' || bytes[1] != b'a' || bytes[2] != b'y' {
+        panic!("day modules must be named `dayNN`");
+    }
+
+    let mut day: u32 = 0;
+    let mut i: usize = 3;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            panic!("day modules must be named `dayNN`");
+        }
+        day = day * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+
+    if day == 0 || day > 25 {
+        panic!("day number must be between 1 and 25");
+    }
+    day as u8
+}
+
+// Declares the day modules of a year crate and builds its `DAYS` table. Meant to be
+// invoked once from the crate root; adding a day is one more line here:
+//
+//     aoc_core::register_days! {
+//         year: 2025,
+//         day01::Day01 => "Secret Entrance",
+//     }
+//
+// The day number is taken from the module name. It also adds a test that checks every
+// day against the year's confirmed answers, see `answers::check_answers`.
+#[macro_export]
+macro_rules! register_days {
+    (year: $year:literal, $($module:ident :: $solver:ident => $name:literal),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const YEAR: u16 = $year;
+
+        pub static DAYS: &[$crate::Day] = &[
+            $($crate::Day {
+                year: $year,
+                day: $crate::registry::day_number(stringify!($module)),
+                name: $name,
+                solver: &$module::$solver,
+            },)*
+        ];
+
+        #[cfg(test)]
+        #[test]
+        fn test_known_answers() {
+            $crate::answers::check_answers(DAYS);
+        }
+    };
+}
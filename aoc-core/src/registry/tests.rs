@@ -0,0 +1,87 @@
+use super::{Day, Registry, day_number};
+use crate::{Answer, SolveError, Solver};
+
+struct Constant;
+
+impl Solver for Constant {
+    type Parsed = ();
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(1))
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(2))
+    }
+}
+
+static YEAR_A: &[Day] = &[
+    Day {
+        year: 2024,
+        day: 3,
+        name: "Third",
+        solver: &Constant,
+    },
+    Day {
+        year: 2024,
+        day: 1,
+        name: "First",
+        solver: &Constant,
+    },
+];
+
+static YEAR_B: &[Day] = &[Day {
+    year: 2023,
+    day: 25,
+    name: "Last",
+    solver: &Constant,
+}];
+
+static REGISTRY: Registry = Registry::new(&[YEAR_A, YEAR_B]);
+
+#[test]
+fn day_number_is_read_from_the_module_name() {
+    const DAY: u8 = day_number("day07");
+    assert_eq!(DAY, 7);
+    assert_eq!(day_number("day1"), 1);
+    assert_eq!(day_number("day25"), 25);
+}
+
+#[test]
+#[should_panic(expected = "dayNN")]
+fn day_number_rejects_other_names() {
+    day_number("part07");
+}
+
+#[test]
+#[should_panic(expected = "between 1 and 25")]
+fn day_number_rejects_out_of_range_days() {
+    day_number("day26");
+}
+
+#[test]
+fn registry_lists_days_in_order() {
+    let listed: Vec<(u16, u8, &str)> = REGISTRY
+        .days()
+        .map(|d: &Day| (d.year, d.day, d.name))
+        .collect();
+    assert_eq!(
+        listed,
+        vec![(2023, 25, "Last"), (2024, 1, "First"), (2024, 3, "Third")]
+    );
+    assert_eq!(REGISTRY.years(), vec![2023, 2024]);
+}
+
+#[test]
+fn registry_looks_up_days() {
+    let day: &Day = REGISTRY.get(2024, 3).expect("registered day");
+    assert_eq!(day.name, "Third");
+    assert_eq!(day.solver.prepare("").unwrap().part2().unwrap(), "2");
+
+    assert!(REGISTRY.get(2024, 2).is_none());
+    assert!(REGISTRY.get(2022, 1).is_none());
+}
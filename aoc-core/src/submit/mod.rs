@@ -0,0 +1,424 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fetch::{Client, FetchError};
+use crate::input::InputLocator;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::{Chars, FromStr};
+
+// The ledger of submitted answers, kept per year next to the confirmed answers, see
+// `InputLocator::year_file`.
+pub const LEDGER_FILE: &str = "submissions.log";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Submitted before the cooldown of an earlier answer ran out; not judged.
+    TooRecent,
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too-recent",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::TooHigh => f.write_str("wrong, too high"),
+            Verdict::TooLow => f.write_str("wrong, too low"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::TooRecent => f.write_str("not judged, an answer was given too recently"),
+            Verdict::AlreadySolved => f.write_str("this part is already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+// What the site said about a submitted answer, plus how long to wait before the next
+// one, if it said so.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    pub wait: Option<u64>,
+}
+
+// The text of the page's `<article>`, with tags dropped and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let start: usize = html.find("<article").unwrap_or(0);
+    let end: usize = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |i: usize| start + i);
+
+    let mut text: String = String::new();
+    let mut in_tag: bool = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// Reads durations like "1m 5s", "34s", "one minute" or "5 minutes" after `marker`.
+fn parse_wait(text: &str, marker: &str) -> Option<u64> {
+    let rest: &str = &text[text.find(marker)? + marker.len()..];
+    let mut seconds: u64 = 0;
+    let mut words = rest.split_whitespace();
+    while let Some(word) = words.next() {
+        let (number, unit): (u64, &str) =
+            if let Some(n) = word.strip_suffix('s').and_then(|n: &str| n.parse().ok()) {
+                (n, "s")
+            } else if let Some(n) = word.strip_suffix('m').and_then(|n: &str| n.parse().ok()) {
+                (n, "m")
+            } else {
+                let number: u64 = match word {
+                    "one" | "a" => 1,
+                    _ => match word.parse() {
+                        Ok(n) => n,
+                        Err(_) => break,
+                    },
+                };
+                let unit: &str = words.next().unwrap_or("");
+                (number, if unit.starts_with("minute") { "m" } else { "s" })
+            };
+        seconds += if unit == "m" { number * 60 } else { number };
+    }
+    (seconds > 0).then_some(seconds)
+}
+
+pub fn parse_reply(html: &str) -> Reply {
+    let text: String = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Reply {
+            verdict: Verdict::Correct,
+            wait: None,
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Reply {
+            verdict: Verdict::TooRecent,
+            wait: parse_wait(&text, "You have"),
+        }
+    } else if text.contains("That's not the right answer") {
+        let verdict: Verdict = if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        Reply {
+            verdict,
+            wait: parse_wait(&text.to_lowercase(), "please wait"),
+        }
+    } else if text.contains("Did you already complete it") {
+        Reply {
+            verdict: Verdict::AlreadySolved,
+            wait: None,
+        }
+    } else {
+        Reply {
+            verdict: Verdict::Unknown(text),
+            wait: None,
+        }
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, FetchError> {
+    let level: String = part.to_string();
+    let page: String = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    Ok(parse_reply(&page))
+}
+
+// One line of the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    // Seconds since the Unix epoch.
+    pub time: u64,
+    pub verdict: Verdict,
+    pub wait: Option<u64>,
+    pub answer: String,
+}
+
+// Ledger fields are kept on one line: backslashes, tabs and line breaks are written as
+// `\\`, `\t`, `\n` and `\r`.
+fn escape(field: &str) -> String {
+    let mut out: String = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(field: &str) -> Result<String, String> {
+    let mut out: String = String::with_capacity(field.len());
+    let mut chars: Chars<'_> = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            other => {
+                let after: String = other.map_or(String::new(), String::from);
+                return Err(format!("bad escape \"\\{after}\" in {field:?}"));
+            }
+        }
+    }
+    Ok(out)
+}
+
+impl fmt::Display for Submission {
+    // Tab-separated, answer last so it may contain spaces. An unrecognised response is
+    // kept in the verdict field as `unknown:<text>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wait: String = self.wait.map_or("-".to_string(), |w: u64| w.to_string());
+        let verdict: String = match &self.verdict {
+            Verdict::Unknown(text) if !text.is_empty() => format!("unknown:{}", escape(text)),
+            verdict => verdict.name().to_string(),
+        };
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.year,
+            self.day,
+            self.part,
+            self.time,
+            verdict,
+            wait,
+            escape(&self.answer)
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.splitn(7, '\t').collect();
+        let [year, day, part, time, verdict, wait, answer] = fields[..] else {
+            return Err(format!("expected 7 tab-separated fields in {line:?}"));
+        };
+        let number = |s: &str| -> Result<u64, String> {
+            s.parse()
+                .map_err(|_| format!("bad number {s:?} in {line:?}"))
+        };
+        let out_of_range = |s: &str| -> String { format!("{s} is out of range in {line:?}") };
+
+        let verdict: Verdict = match verdict {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "too-recent" => Verdict::TooRecent,
+            "already-solved" => Verdict::AlreadySolved,
+            "unknown" => Verdict::Unknown(String::new()),
+            _ => match verdict.strip_prefix("unknown:") {
+                Some(text) => Verdict::Unknown(unescape(text)?),
+                None => return Err(format!("unknown verdict {verdict:?} in {line:?}")),
+            },
+        };
+
+        Ok(Submission {
+            year: u16::try_from(number(year)?).map_err(|_| out_of_range(year))?,
+            day: u8::try_from(number(day)?).map_err(|_| out_of_range(day))?,
+            part: u8::try_from(number(part)?).map_err(|_| out_of_range(part))?,
+            time: number(time)?,
+            verdict,
+            wait: if wait == "-" {
+                None
+            } else {
+                Some(number(wait)?)
+            },
+            answer: unescape(answer)?,
+        })
+    }
+}
+
+// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong,
+    NotBelow(String),
+    NotAbove(String),
+    Wait(u64),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "this part is already solved with {answer}")
+            }
+            Refusal::KnownWrong => f.write_str("this answer was already rejected"),
+            Refusal::NotBelow(bound) => write!(f, "{bound} was too high, so this answer is too"),
+            Refusal::NotAbove(bound) => write!(f, "{bound} was too low, so this answer is too"),
+            Refusal::Wait(seconds) => write!(f, "the site asked to wait, {seconds}s to go"),
+        }
+    }
+}
+
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl Ledger {
+    pub fn path_for(locator: &InputLocator, year: u16) -> PathBuf {
+        locator.year_file(year, LEDGER_FILE)
+    }
+
+    // A missing ledger is an empty one.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Ledger> {
+        let path: PathBuf = path.into();
+        let text: String = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let entries: Vec<Submission> = text
+            .lines()
+            .filter(|l: &&str| !l.trim().is_empty())
+            .map(|l: &str| l.parse::<Submission>())
+            .collect::<Result<_, String>>()
+            .map_err(|e: String| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            })?;
+
+        Ok(Ledger { path, entries })
+    }
+
+    pub fn entries(&self) -> &[Submission] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, submission: Submission) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file: fs::File = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", submission)?;
+        self.entries.push(submission);
+        Ok(())
+    }
+
+    // Checks an answer against everything learned from earlier submissions of the
+    // same part: a known correct answer, rejected answers, too-high/too-low bounds
+    // (for numeric answers) and a pending cooldown.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let earlier: Vec<&Submission> = self
+            .entries
+            .iter()
+            .filter(|s: &&Submission| s.year == year && s.day == day && s.part == part)
+            .collect();
+
+        if let Some(correct) = earlier
+            .iter()
+            .find(|s: &&&Submission| s.verdict == Verdict::Correct)
+        {
+            return Err(Refusal::AlreadyCorrect(correct.answer.clone()));
+        }
+        if earlier
+            .iter()
+            .any(|s: &&Submission| s.verdict.is_wrong() && s.answer == answer)
+        {
+            return Err(Refusal::KnownWrong);
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            for s in &earlier {
+                let Ok(bound) = s.answer.parse::<i128>() else {
+                    continue;
+                };
+                match s.verdict {
+                    Verdict::TooHigh if value >= bound => {
+                        return Err(Refusal::NotBelow(s.answer.clone()));
+                    }
+                    Verdict::TooLow if value <= bound => {
+                        return Err(Refusal::NotAbove(s.answer.clone()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // A cooldown holds back every part, not just the one that caused it.
+        let wait_until: u64 = self
+            .entries
+            .iter()
+            .filter_map(|s: &Submission| s.wait.map(|w: u64| s.time + w))
+            .max()
+            .unwrap_or(0);
+        if now < wait_until {
+            return Err(Refusal::Wait(wait_until - now));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use super::{Ledger, Refusal, Reply, Submission, Verdict, parse_reply, submit};
+use crate::fetch::Client;
+use crate::http::stub::{StubServer, response};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn page(article: &str) -> String {
+    format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+}
+
+fn submission(
+    part: u8,
+    time: u64,
+    verdict: Verdict,
+    wait: Option<u64>,
+    answer: &str,
+) -> Submission {
+    Submission {
+        year: 2025,
+        day: 9,
+        part,
+        time,
+        verdict,
+        wait,
+        answer: answer.to_string(),
+    }
+}
+
+fn ledger(name: &str) -> Ledger {
+    let path: PathBuf = env::temp_dir()
+        .join(format!("aoc_core_submit_{}_{}", name, std::process::id()))
+        .join("2025/submissions.log");
+    let _ = fs::remove_file(&path);
+    Ledger::load(path).unwrap()
+}
+
+#[test]
+fn recognises_site_replies() {
+    let reply = |article: &str| -> Reply { parse_reply(&page(article)) };
+
+    assert_eq!(
+        reply(
+            "That's the right answer!  You are <em>one gold star</em> closer. <a href=\"/2025/day/9#part2\">[Continue to Part Two]</a>"
+        ),
+        Reply {
+            verdict: Verdict::Correct,
+            wait: None
+        }
+    );
+    assert_eq!(
+        reply(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2025/day/9\">[Return to Day 9]</a>"
+        ),
+        Reply {
+            verdict: Verdict::TooHigh,
+            wait: Some(60)
+        }
+    );
+    assert_eq!(
+        reply(
+            "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."
+        ),
+        Reply {
+            verdict: Verdict::TooLow,
+            wait: Some(300)
+        }
+    );
+    assert_eq!(
+        reply(
+            "That's not the right answer.  If you're stuck, there are some general tips on the <a href=\"/2025/about\">about page</a>."
+        ),
+        Reply {
+            verdict: Verdict::Wrong,
+            wait: None
+        }
+    );
+    assert_eq!(
+        reply(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."
+        ),
+        Reply {
+            verdict: Verdict::TooRecent,
+            wait: Some(65)
+        }
+    );
+    assert_eq!(
+        reply("You don't seem to be solving the right level.  Did you already complete it?"),
+        Reply {
+            verdict: Verdict::AlreadySolved,
+            wait: None
+        }
+    );
+    assert_eq!(
+        reply("Something else <b>entirely</b>.").verdict,
+        Verdict::Unknown("Something else entirely .".to_string())
+    );
+}
+
+#[test]
+fn ledger_lines_round_trip() {
+    let entry: Submission = submission(2, 1_765_000_000, Verdict::TooLow, Some(60), "two words");
+    let line: String = entry.to_string();
+    assert_eq!(line, "2025\t9\t2\t1765000000\ttoo-low\t60\ttwo words");
+    assert_eq!(line.parse::<Submission>(), Ok(entry));
+
+    let odd: Submission = submission(
+        1,
+        7,
+        Verdict::Unknown("Try\tagain \\o/".to_string()),
+        None,
+        "###\n#.#\r\n\\",
+    );
+    let line: String = odd.to_string();
+    assert_eq!(
+        line,
+        "2025\t9\t1\t7\tunknown:Try\\tagain \\\\o/\t-\t###\\n#.#\\r\\n\\\\"
+    );
+    assert_eq!(line.parse::<Submission>(), Ok(odd));
+    assert_eq!(
+        "2025\t9\t1\t7\tunknown\t-\t5"
+            .parse::<Submission>()
+            .unwrap()
+            .verdict,
+        Verdict::Unknown(String::new())
+    );
+
+    assert!("2025\t9\t2".parse::<Submission>().is_err());
+    assert!(
+        "2025\t9\t2\t1\twrong\t-\t5\\x"
+            .parse::<Submission>()
+            .is_err()
+    );
+    assert!("2025\t9\t2\t1\tmaybe\t-\t5".parse::<Submission>().is_err());
+    // A corrupt day must not be read as a different one.
+    assert_eq!(
+        "2025\t265\t2\t1\twrong\t-\t5".parse::<Submission>(),
+        Err("265 is out of range in \"2025\\t265\\t2\\t1\\twrong\\t-\\t5\"".to_string())
+    );
+    assert!("67561\t9\t2\t1\twrong\t-\t5".parse::<Submission>().is_err());
+}
+
+#[test]
+fn ledger_is_appended_and_reloaded() {
+    let mut ledger: Ledger = ledger("reload");
+    ledger
+        .record(submission(1, 100, Verdict::Wrong, None, "12"))
+        .unwrap();
+    ledger
+        .record(submission(1, 200, Verdict::Correct, None, "13"))
+        .unwrap();
+
+    let reloaded: Ledger = Ledger::load(ledger.path.clone()).unwrap();
+    assert_eq!(reloaded.entries(), ledger.entries());
+    assert_eq!(reloaded.entries().len(), 2);
+
+    // Neither a multi-line answer nor an unrecognised response breaks the ledger.
+    ledger
+        .record(submission(
+            2,
+            300,
+            Verdict::Unknown("odd".to_string()),
+            None,
+            "a\nb",
+        ))
+        .unwrap();
+    let reloaded: Ledger = Ledger::load(ledger.path.clone()).unwrap();
+    assert_eq!(reloaded.entries(), ledger.entries());
+}
+
+#[test]
+fn refuses_answers_that_cannot_be_right() {
+    let mut ledger: Ledger = ledger("refuse");
+    ledger
+        .record(submission(2, 100, Verdict::TooHigh, Some(60), "5000"))
+        .unwrap();
+    ledger
+        .record(submission(2, 200, Verdict::TooLow, Some(60), "1000"))
+        .unwrap();
+    ledger
+        .record(submission(2, 300, Verdict::Wrong, Some(60), "abc"))
+        .unwrap();
+
+    let check = |answer: &str, now: u64| ledger.check(2025, 9, 2, answer, now);
+    assert_eq!(check("abc", 1000), Err(Refusal::KnownWrong));
+    assert_eq!(check("5000", 1000), Err(Refusal::KnownWrong));
+    assert_eq!(
+        check("6000", 1000),
+        Err(Refusal::NotBelow("5000".to_string()))
+    );
+    assert_eq!(
+        check("999", 1000),
+        Err(Refusal::NotAbove("1000".to_string()))
+    );
+    assert_eq!(check("1000", 1000), Err(Refusal::KnownWrong));
+    assert_eq!(check("2500", 1000), Ok(()));
+    assert_eq!(check("xyz", 1000), Ok(()));
+    // Still cooling down from the last wrong answer
+    assert_eq!(check("2500", 330), Err(Refusal::Wait(30)));
+    // Other parts and days are not affected by the bounds
+    assert_eq!(ledger.check(2025, 9, 1, "6000", 1000), Ok(()));
+    assert_eq!(ledger.check(2025, 10, 2, "6000", 1000), Ok(()));
+}
+
+#[test]
+fn refuses_anything_once_solved() {
+    let mut ledger: Ledger = ledger("solved");
+    ledger
+        .record(submission(1, 100, Verdict::Correct, None, "42"))
+        .unwrap();
+
+    assert_eq!(
+        ledger.check(2025, 9, 1, "42", 1000),
+        Err(Refusal::AlreadyCorrect("42".to_string()))
+    );
+    assert_eq!(
+        ledger.check(2025, 9, 1, "43", 1000),
+        Err(Refusal::AlreadyCorrect("42".to_string()))
+    );
+    assert_eq!(ledger.check(2025, 9, 2, "43", 1000), Ok(()));
+}
+
+#[test]
+fn submits_to_the_configured_endpoint() {
+    let server: StubServer =
+        StubServer::start(vec![response(200, &page("That's the right answer!"))]);
+    let client: Client = Client::new(&server.base_url).with_session("secret");
+
+    let reply: Reply = submit(&client, 2025, 9, 2, "1525241870").unwrap();
+    assert_eq!(reply.verdict, Verdict::Correct);
+
+    let requests: Vec<String> = server.requests();
+    assert!(requests[0].starts_with("POST /2025/day/9/answer HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+    assert!(requests[0].ends_with("level=2&answer=1525241870"));
+}
@@ -1,34 +1,109 @@
-#[cfg(test)]
-mod tests {
-    use crate::read_input;
-    use std::env;
-    use std::fs;
-    use std::io::Error;
-    use std::path::PathBuf;
-
-    #[test]
-    fn reads_existing_input_file() {
-        // Create a temporary directory and populate the expected path
-        let tmp_dir: PathBuf = env::temp_dir().join("aoc_core_read_input_test");
-        let _ = fs::remove_dir_all(&tmp_dir);
-        fs::create_dir_all(tmp_dir.join("year2025/src/day01")).unwrap();
-        let expected: &str = "L1\nR2\nL3\n";
-        fs::write(tmp_dir.join("year2025/src/day01/input.txt"), expected).unwrap();
-
-        // Save the current dir and switch to the temp dir
-        let original_dir: PathBuf = env::current_dir().unwrap();
-        env::set_current_dir(&tmp_dir).unwrap();
-
-        let content: String = read_input(2025, 1).expect("should read existing input file");
-        assert_eq!(content, expected);
-
-        // Restore original directory
-        env::set_current_dir(original_dir).unwrap();
-    }
-
-    #[test]
-    fn missing_input_file_returns_error() {
-        let err: Error = read_input(2099, 99).unwrap_err();
-        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+use crate::{Answer, DynSolver, Prepared, SolveError, Solver, read_input};
+use std::io::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn missing_input_file_returns_error() {
+    let err: Error = read_input(2099, 99).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+struct LineCounter {
+    parses: AtomicUsize,
+}
+
+impl Solver for LineCounter {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        self.parses.fetch_add(1, Ordering::Relaxed);
+        Ok(input.lines().map(str::len).collect())
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(parsed.len()))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(parsed.iter().sum::<usize>()))
+    }
+}
+
+#[test]
+fn solve_parses_once_for_both_parts() {
+    let solver = LineCounter {
+        parses: AtomicUsize::new(0),
+    };
+    let result: [Answer; 2] = solver.solve("ab\ncde\n").unwrap();
+
+    assert_eq!(result, [Answer::Int(2), Answer::Int(5)]);
+    assert_eq!(solver.parses.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn dyn_solver_shares_the_parsed_input() {
+    let solver = LineCounter {
+        parses: AtomicUsize::new(0),
+    };
+    let erased: &dyn DynSolver = &solver;
+    let prepared: Box<dyn Prepared + '_> = erased.prepare("a\nbb\nccc").unwrap();
+
+    assert_eq!(prepared.part1(), Ok(Answer::Int(3)));
+    assert_eq!(prepared.part2(), Ok(Answer::Int(6)));
+    assert_eq!(solver.parses.load(Ordering::Relaxed), 1);
+    assert!(erased.parses());
+}
+
+struct Unparsed;
+
+impl Solver for Unparsed {
+    type Parsed = ();
+    const PARSES: bool = false;
+    const IMPLEMENTED: bool = false;
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
     }
 }
+
+// Parses into `()`, but only to check the input.
+struct Validating;
+
+impl Solver for Validating {
+    type Parsed = ();
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        if input.is_empty() {
+            return Err(SolveError::InvalidInput("empty input".to_string()));
+        }
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(1))
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(2))
+    }
+}
+
+#[test]
+fn only_solvers_that_say_so_have_no_parse_phase() {
+    assert!(!(&Unparsed as &dyn DynSolver).parses());
+    assert!((&Validating as &dyn DynSolver).parses());
+}
+
+#[test]
+fn solvers_are_implemented_unless_they_say_otherwise() {
+    assert!(!(&Unparsed as &dyn DynSolver).implemented());
+    assert!((&Validating as &dyn DynSolver).implemented());
+}
@@ -0,0 +1,333 @@
+#[cfg(test)]
+mod tests;
+
+use crate::cli::Format;
+use crate::json::{self, Object};
+use crate::report::{self, format_duration};
+use aoc_core::{Day, Prepared, SolveError};
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// The results of the last benchmark, in the workspace root. Timings only mean something
+// on the machine that took them, so the file is git-ignored.
+pub const BENCH_FILE: &str = "bench.json";
+
+pub const DEFAULT_RUNS: usize = 10;
+
+// Every phase is run untimed for at least this long before it is measured.
+const WARMUP: Duration = Duration::from_millis(100);
+
+// A phase has regressed when its median grew by more than this factor and even its
+// fastest run is slower than the last median, which keeps noise from being flagged.
+const REGRESSION: f64 = 1.10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        Phase::ALL.into_iter().find(|p: &Phase| p.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted: Vec<Duration> = samples.to_vec();
+        sorted.sort();
+        let n: usize = sorted.len();
+        let median: Duration = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let mean: f64 = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance: f64 = sorted
+            .iter()
+            .map(|d: &Duration| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    pub fn regressed_from(&self, last: &Stats) -> bool {
+        self.median.as_secs_f64() > last.median.as_secs_f64() * REGRESSION && self.min > last.median
+    }
+}
+
+// The timings of one phase of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+impl Measurement {
+    fn key(&self) -> (u16, u8, Phase) {
+        (self.year, self.day, self.phase)
+    }
+}
+
+// Runs `f` untimed until the warm-up is over, then times it `runs` times.
+fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    let warmup: Instant = Instant::now();
+    while warmup.elapsed() < WARMUP {
+        black_box(f());
+    }
+
+    (0..runs)
+        .map(|_| {
+            let start: Instant = Instant::now();
+            let result: T = f();
+            let elapsed: Duration = start.elapsed();
+            drop(black_box(result));
+            elapsed
+        })
+        .collect()
+}
+
+// Times the parse phase (if the solver has one) and the given parts of one day. Errors
+// are not expected here: the day should have run successfully once already.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    parts: [bool; 2],
+    runs: usize,
+) -> Result<Vec<Measurement>, SolveError> {
+    let measurement = |phase: Phase, samples: Vec<Duration>| Measurement {
+        year: day.year,
+        day: day.day,
+        phase,
+        stats: Stats::from_samples(&samples),
+    };
+
+    let mut measurements: Vec<Measurement> = Vec::new();
+    if day.solver.parses() {
+        let samples: Vec<Duration> = sample(runs, || day.solver.prepare(input));
+        measurements.push(measurement(Phase::Parse, samples));
+    }
+
+    let prepared: Box<dyn Prepared + '_> = day.solver.prepare(input)?;
+    if parts[0] {
+        prepared.part1()?;
+        measurements.push(measurement(Phase::Part1, sample(runs, || prepared.part1())));
+    }
+    if parts[1] {
+        prepared.part2()?;
+        measurements.push(measurement(Phase::Part2, sample(runs, || prepared.part2())));
+    }
+    Ok(measurements)
+}
+
+// The change of a median relative to the last run, e.g. `+12.5%`.
+fn change(stats: &Stats, last: Option<&Stats>) -> String {
+    let Some(last) = last else {
+        return String::new();
+    };
+    let ratio: f64 = stats.median.as_secs_f64() / last.median.as_secs_f64().max(1e-9);
+    let flag: &str = if stats.regressed_from(last) {
+        "  regression"
+    } else {
+        ""
+    };
+    format!("{:+.1}%{}", (ratio - 1.0) * 100.0, flag)
+}
+
+// One row per phase, with the change since the last run where there was one.
+pub fn phase_table(days: &[&Day], measurements: &[Measurement], last: &[Measurement]) -> String {
+    let last: BTreeMap<(u16, u8, Phase), &Stats> = last
+        .iter()
+        .map(|m: &Measurement| (m.key(), &m.stats))
+        .collect();
+
+    let mut rows: Vec<Vec<String>> = vec![
+        [
+            "Day", "Name", "Phase", "Min", "Median", "Mean", "Std dev", "Change",
+        ]
+        .map(str::to_string)
+        .to_vec(),
+    ];
+    for day in days {
+        let mut first: bool = true;
+        for m in measurements
+            .iter()
+            .filter(|m: &&Measurement| (m.year, m.day) == (day.year, day.day))
+        {
+            let (label, name): (String, String) = if first {
+                (format!("{}-{:02}", day.year, day.day), day.name.to_string())
+            } else {
+                (String::new(), String::new())
+            };
+            first = false;
+            rows.push(vec![
+                label,
+                name,
+                m.phase.name().to_string(),
+                format_duration(m.stats.min),
+                format_duration(m.stats.median),
+                format_duration(m.stats.mean),
+                format_duration(m.stats.stddev),
+                change(&m.stats, last.get(&m.key()).copied()),
+            ]);
+        }
+    }
+
+    report::table(&rows, &[false, false, false, true, true, true, true, true])
+}
+
+// The summed medians of every phase, one row per year.
+pub fn year_table(measurements: &[Measurement]) -> String {
+    let mut years: BTreeMap<u16, (Vec<u8>, [Duration; 3])> = BTreeMap::new();
+    for m in measurements {
+        let (days, totals): &mut (Vec<u8>, [Duration; 3]) = years.entry(m.year).or_default();
+        if days.last() != Some(&m.day) {
+            days.push(m.day);
+        }
+        totals[m.phase as usize] += m.stats.median;
+    }
+
+    let mut rows: Vec<Vec<String>> = vec![
+        ["Year", "Days", "Parse", "Part 1", "Part 2", "Total"]
+            .map(str::to_string)
+            .to_vec(),
+    ];
+    for (year, (days, totals)) in &years {
+        rows.push(vec![
+            year.to_string(),
+            days.len().to_string(),
+            format_duration(totals[0]),
+            format_duration(totals[1]),
+            format_duration(totals[2]),
+            format_duration(totals.iter().sum()),
+        ]);
+    }
+    report::table(&rows, &[false, true, true, true, true, true])
+}
+
+pub fn regressions(measurements: &[Measurement], last: &[Measurement]) -> usize {
+    measurements
+        .iter()
+        .filter(|m: &&Measurement| {
+            last.iter()
+                .any(|l: &Measurement| l.key() == m.key() && m.stats.regressed_from(&l.stats))
+        })
+        .count()
+}
+
+// The last results with the phases that were just measured replaced, so benchmarking
+// a single day keeps the results of all others.
+pub fn merge(last: &[Measurement], measurements: &[Measurement]) -> Vec<Measurement> {
+    let mut merged: BTreeMap<(u16, u8, Phase), Measurement> = last
+        .iter()
+        .map(|m: &Measurement| (m.key(), m.clone()))
+        .collect();
+    for m in measurements {
+        merged.insert(m.key(), m.clone());
+    }
+    merged.into_values().collect()
+}
+
+// One JSON object per measurement, durations in nanoseconds.
+pub fn json_records(measurements: &[Measurement]) -> Vec<String> {
+    measurements
+        .iter()
+        .map(|m: &Measurement| {
+            format!(
+                "{{\"year\":{},\"day\":{},\"phase\":{},\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+                m.year,
+                m.day,
+                json::quote(m.phase.name()),
+                m.stats.runs,
+                m.stats.min.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.mean.as_nanos(),
+                m.stats.stddev.as_nanos()
+            )
+        })
+        .collect()
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    report::json_output(&json_records(measurements), Format::Json)
+}
+
+fn measurement(fields: &Object) -> Result<Measurement, String> {
+    let year: u16 =
+        u16::try_from(json::number(fields, "year")?).map_err(|_| "`year` is out of range")?;
+    let day: u8 =
+        u8::try_from(json::number(fields, "day")?).map_err(|_| "`day` is out of range")?;
+    let phase: &str = json::string(fields, "phase")?;
+    let phase: Phase =
+        Phase::from_name(phase).ok_or_else(|| format!("unknown phase `{}`", phase))?;
+    let nanos = |key: &str| -> Result<Duration, String> {
+        json::number(fields, key).map(Duration::from_nanos)
+    };
+
+    Ok(Measurement {
+        year,
+        day,
+        phase,
+        stats: Stats {
+            runs: json::number(fields, "runs")? as usize,
+            min: nanos("min_ns")?,
+            median: nanos("median_ns")?,
+            mean: nanos("mean_ns")?,
+            stddev: nanos("stddev_ns")?,
+        },
+    })
+}
+
+pub fn parse_json(text: &str) -> Result<Vec<Measurement>, String> {
+    json::parse_array(text)?.iter().map(measurement).collect()
+}
+
+// A missing file means there is nothing to compare with yet.
+pub fn load(path: &Path) -> io::Result<Vec<Measurement>> {
+    let text: String = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    parse_json(&text).map_err(|e: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
@@ -0,0 +1,196 @@
+use super::{
+    Measurement, Phase, Stats, bench_day, merge, parse_json, phase_table, regressions, to_json,
+    year_table,
+};
+use aoc_core::{Answer, Day, SolveError, Solver};
+use std::time::Duration;
+
+struct Lengths;
+
+impl Solver for Lengths {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.lines().map(str::len).collect())
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(parsed.iter().sum::<usize>()))
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+}
+
+struct Unparsed;
+
+impl Solver for Unparsed {
+    type Parsed = ();
+    const PARSES: bool = false;
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(1))
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(2))
+    }
+}
+
+static LENGTHS: Day = Day {
+    year: 2025,
+    day: 1,
+    name: "Lengths",
+    solver: &Lengths,
+};
+
+static UNPARSED: Day = Day {
+    year: 2025,
+    day: 2,
+    name: "Unparsed",
+    solver: &Unparsed,
+};
+
+fn stats(median_ms: u64) -> Stats {
+    Stats {
+        runs: 10,
+        min: Duration::from_millis(median_ms - 1),
+        median: Duration::from_millis(median_ms),
+        mean: Duration::from_millis(median_ms),
+        stddev: Duration::from_micros(250),
+    }
+}
+
+fn measurement(day: u8, phase: Phase, median_ms: u64) -> Measurement {
+    Measurement {
+        year: 2025,
+        day,
+        phase,
+        stats: stats(median_ms),
+    }
+}
+
+#[test]
+fn computes_statistics_of_samples() {
+    let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+    let stats: Stats = Stats::from_samples(&samples);
+
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2_500));
+    assert_eq!(stats.mean, Duration::from_micros(2_500));
+    assert_eq!(stats.stddev.as_micros(), 1_118);
+
+    let odd: Stats = Stats::from_samples(&[3, 9, 5].map(Duration::from_millis));
+    assert_eq!(odd.median, Duration::from_millis(5));
+}
+
+#[test]
+fn benches_parsing_only_where_it_does_something() {
+    let measured: Vec<Measurement> = bench_day(&LENGTHS, "ab\nc\n", [true, false], 3).unwrap();
+    let phases: Vec<Phase> = measured.iter().map(|m: &Measurement| m.phase).collect();
+    assert_eq!(phases, vec![Phase::Parse, Phase::Part1]);
+    assert!(measured.iter().all(|m: &Measurement| m.stats.runs == 3));
+
+    let measured: Vec<Measurement> = bench_day(&UNPARSED, "", [true, true], 2).unwrap();
+    let phases: Vec<Phase> = measured.iter().map(|m: &Measurement| m.phase).collect();
+    assert_eq!(phases, vec![Phase::Part1, Phase::Part2]);
+
+    assert_eq!(
+        bench_day(&LENGTHS, "", [false, true], 2),
+        Err(SolveError::NotImplemented)
+    );
+}
+
+#[test]
+fn results_survive_a_round_trip_through_json() {
+    let measurements: Vec<Measurement> = vec![
+        measurement(1, Phase::Parse, 2),
+        measurement(1, Phase::Part2, 40),
+    ];
+    let json: String = to_json(&measurements);
+    assert!(
+        json.starts_with(
+            "[\n  {\"year\":2025,\"day\":1,\"phase\":\"parse\",\"runs\":10,\"min_ns\":1000000,"
+        ),
+        "{json}"
+    );
+
+    assert_eq!(parse_json(&json).unwrap(), measurements);
+    assert_eq!(parse_json(&to_json(&[])).unwrap(), vec![]);
+}
+
+#[test]
+fn rejects_malformed_results() {
+    assert!(parse_json("").is_err());
+    assert!(parse_json("[{\"year\":2025}]").unwrap_err().contains("day"));
+    assert!(parse_json("[{\"year\":-1}]").is_err());
+    assert!(
+        parse_json("[{\"year\":2025,\"day\":1,\"phase\":\"part3\"}]")
+            .unwrap_err()
+            .contains("part3")
+    );
+    assert!(parse_json("[] []").is_err());
+}
+
+#[test]
+fn merging_keeps_other_days() {
+    let last: Vec<Measurement> = vec![
+        measurement(1, Phase::Part1, 2),
+        measurement(2, Phase::Part1, 5),
+    ];
+    let merged: Vec<Measurement> = merge(&last, &[measurement(2, Phase::Part1, 7)]);
+    assert_eq!(
+        merged,
+        vec![
+            measurement(1, Phase::Part1, 2),
+            measurement(2, Phase::Part1, 7)
+        ]
+    );
+}
+
+#[test]
+fn flags_phases_that_got_slower() {
+    let last: Vec<Measurement> = vec![
+        measurement(1, Phase::Parse, 10),
+        measurement(1, Phase::Part1, 10),
+    ];
+    let measurements: Vec<Measurement> = vec![
+        measurement(1, Phase::Parse, 10),
+        measurement(1, Phase::Part1, 20),
+        measurement(1, Phase::Part2, 3),
+    ];
+    assert_eq!(regressions(&measurements, &last), 1);
+    // Within the noise: the fastest run is not slower than the last median.
+    assert_eq!(regressions(&[measurement(1, Phase::Parse, 11)], &last), 0);
+
+    let table: String = phase_table(&[&LENGTHS], &measurements, &last);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 4, "{table}");
+    assert!(lines[0].starts_with("Day      Name     Phase"), "{table}");
+    assert!(lines[1].starts_with("2025-01  Lengths  parse"), "{table}");
+    assert!(lines[1].ends_with("+0.0%"), "{table}");
+    assert!(lines[2].starts_with("                  part1"), "{table}");
+    assert!(lines[2].ends_with("+100.0%  regression"), "{table}");
+    assert!(lines[3].ends_with("250.0 µs"), "{table}");
+}
+
+#[test]
+fn year_table_sums_medians() {
+    let measurements: Vec<Measurement> = vec![
+        measurement(1, Phase::Parse, 2),
+        measurement(1, Phase::Part1, 3),
+        measurement(2, Phase::Part1, 4),
+        measurement(2, Phase::Part2, 10),
+    ];
+    assert_eq!(
+        year_table(&measurements),
+        "Year  Days   Parse  Part 1   Part 2    Total\n\
+         2025     2  2.0 ms  7.0 ms  10.0 ms  19.0 ms\n"
+    );
+}
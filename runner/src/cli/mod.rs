@@ -0,0 +1,412 @@
+#[cfg(test)]
+mod tests;
+
+use crate::bench::DEFAULT_RUNS;
+use crate::target::Target;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandKind {
+    Run,
+    Watch,
+    Bench,
+    Test,
+    List,
+    New,
+    Fetch,
+    Puzzle,
+    Submit,
+    Verify,
+}
+
+struct CommandSpec {
+    kind: CommandKind,
+    name: &'static str,
+    target: &'static str,
+    target_required: bool,
+    help: &'static str,
+}
+
+const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        kind: CommandKind::Run,
+        name: "run",
+        target: "<target>",
+        target_required: true,
+        help: "Run solvers and print their answers",
+    },
+    CommandSpec {
+        kind: CommandKind::Watch,
+        name: "watch",
+        target: "<target>",
+        target_required: true,
+        help: "Run solvers again whenever their sources or inputs change",
+    },
+    CommandSpec {
+        kind: CommandKind::Bench,
+        name: "bench",
+        target: "<target>",
+        target_required: true,
+        help: "Time solvers over repeated runs",
+    },
+    CommandSpec {
+        kind: CommandKind::Test,
+        name: "test",
+        target: "<target>",
+        target_required: true,
+        help: "Run the unit tests of the selected days",
+    },
+    CommandSpec {
+        kind: CommandKind::List,
+        name: "list",
+        target: "[target]",
+        target_required: false,
+        help: "List registered days",
+    },
+    CommandSpec {
+        kind: CommandKind::New,
+        name: "new",
+        target: "<year-day>",
+        target_required: true,
+        help: "Create the files for a new day",
+    },
+    CommandSpec {
+        kind: CommandKind::Fetch,
+        name: "fetch",
+        target: "<target>",
+        target_required: true,
+        help: "Download puzzle inputs",
+    },
+    CommandSpec {
+        kind: CommandKind::Puzzle,
+        name: "puzzle",
+        target: "<target>",
+        target_required: true,
+        help: "Download puzzle descriptions and examples",
+    },
+    CommandSpec {
+        kind: CommandKind::Submit,
+        name: "submit",
+        target: "<year-day>",
+        target_required: true,
+        help: "Submit an answer (requires --part)",
+    },
+    CommandSpec {
+        kind: CommandKind::Verify,
+        name: "verify",
+        target: "[target]",
+        target_required: false,
+        help: "Check answers against the confirmed ones in answers.toml",
+    },
+];
+
+struct FlagSpec {
+    name: &'static str,
+    value: Option<&'static str>,
+    help: &'static str,
+}
+
+const FLAGS: &[FlagSpec] = &[
+    FlagSpec {
+        name: "--input",
+        value: Some("<path>"),
+        help: "Read the puzzle input from a file (single day only)",
+    },
+    FlagSpec {
+        name: "--stdin",
+        value: None,
+        help: "Read the puzzle input from standard input (single day only)",
+    },
+    FlagSpec {
+        name: "--example",
+        value: Some("<n>"),
+        help: "Use the day's example<n>.in as the input",
+    },
+    FlagSpec {
+        name: "--allow-empty",
+        value: None,
+        help: "Run on missing or empty input instead of failing",
+    },
+    FlagSpec {
+        name: "--part",
+        value: Some("1|2"),
+        help: "Only run the given part",
+    },
+    FlagSpec {
+        name: "--format",
+        value: Some("text|json|ndjson"),
+        help: "Output format (default: text)",
+    },
+    FlagSpec {
+        name: "--jobs",
+        value: Some("<n>"),
+        help: "Days to solve at once (default: one per CPU)",
+    },
+    FlagSpec {
+        name: "--runs",
+        value: Some("<n>"),
+        help: "Timed runs per phase for `bench` (default: 10)",
+    },
+    FlagSpec {
+        name: "--quiet",
+        value: None,
+        help: "Only print answers",
+    },
+    FlagSpec {
+        name: "--help",
+        value: None,
+        help: "Print this help",
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    // One JSON record per line, for tools that read the output as it comes.
+    Ndjson,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+    // An example saved by `puzzle` next to the day's solution, by number.
+    Example(usize),
+}
+
+impl InputSource {
+    fn flag(&self) -> &'static str {
+        match self {
+            InputSource::Default => "",
+            InputSource::File(_) => "--input",
+            InputSource::Stdin => "--stdin",
+            InputSource::Example(_) => "--example",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub input: InputSource,
+    pub allow_empty: bool,
+    pub part: Option<u8>,
+    pub format: Format,
+    pub runs: usize,
+    // `None` means one per CPU.
+    pub jobs: Option<usize>,
+    pub quiet: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            input: InputSource::Default,
+            allow_empty: false,
+            part: None,
+            format: Format::Text,
+            runs: DEFAULT_RUNS,
+            jobs: None,
+            quiet: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cli {
+    Help,
+    Command {
+        kind: CommandKind,
+        target: Option<Target>,
+        options: Options,
+    },
+}
+
+// A mistake on the command line. Shown together with a pointer to `--help`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error: {}\n\nRun `runner --help` for usage.", self.0)
+    }
+}
+
+fn usage_error<T>(message: impl Into<String>) -> Result<T, UsageError> {
+    Err(UsageError(message.into()))
+}
+
+pub fn help() -> String {
+    let mut out: String = String::from("Usage: runner <command> [target] [options]\n\nCommands:\n");
+
+    let commands: Vec<(String, &str)> = COMMANDS
+        .iter()
+        .map(|c: &CommandSpec| (format!("{} {}", c.name, c.target), c.help))
+        .collect();
+    let flags: Vec<(String, &str)> = FLAGS
+        .iter()
+        .map(|f: &FlagSpec| match f.value {
+            Some(value) => (format!("{} {}", f.name, value), f.help),
+            None => (f.name.to_string(), f.help),
+        })
+        .collect();
+    let width: usize = commands
+        .iter()
+        .chain(&flags)
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+
+    for (usage, text) in &commands {
+        out.push_str(&format!("  {:<width$}  {}\n", usage, text));
+    }
+    out.push_str("\nTargets:\n");
+    out.push_str("  2025-01, 2025-03..07, 2025-01,04,09, 2025, all\n");
+    out.push_str("  A bare target without a command is the same as `run <target>`.\n");
+    out.push_str("\nOptions:\n");
+    for (usage, text) in &flags {
+        out.push_str(&format!("  {:<width$}  {}\n", usage, text));
+    }
+    out
+}
+
+// Parses the arguments after the program name.
+pub fn parse(args: &[String]) -> Result<Cli, UsageError> {
+    let mut options: Options = Options::default();
+    let mut positional: Vec<&str> = Vec::new();
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        // Both `--flag value` and `--flag=value` are accepted.
+        let (flag, inline): (&str, Option<&str>) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| -> Result<String, UsageError> {
+            match inline {
+                Some(v) => Ok(v.to_string()),
+                None => match it.next() {
+                    Some(v) => Ok(v.clone()),
+                    None => usage_error(format!("`{name}` needs a value")),
+                },
+            }
+        };
+
+        match flag {
+            "--help" | "-h" => return Ok(Cli::Help),
+            "--input" | "--stdin" | "--example" => {
+                let source: InputSource = match flag {
+                    "--input" => InputSource::File(PathBuf::from(value("--input")?)),
+                    "--stdin" => InputSource::Stdin,
+                    _ => {
+                        let n: String = value("--example")?;
+                        match n.parse() {
+                            Ok(n) if n > 0 => InputSource::Example(n),
+                            _ => {
+                                return usage_error(format!(
+                                    "invalid example `{n}`, expected a positive number"
+                                ));
+                            }
+                        }
+                    }
+                };
+                if options.input != InputSource::Default && options.input.flag() != flag {
+                    return usage_error(format!(
+                        "`{}` and `{}` cannot be combined",
+                        options.input.flag(),
+                        flag
+                    ));
+                }
+                options.input = source;
+            }
+            "--allow-empty" => options.allow_empty = true,
+            "--part" => {
+                let part: String = value("--part")?;
+                options.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return usage_error(format!("invalid part `{part}`, expected 1 or 2")),
+                };
+            }
+            "--format" => {
+                let format: String = value("--format")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "ndjson" => Format::Ndjson,
+                    _ => {
+                        return usage_error(format!(
+                            "invalid format `{format}`, expected `text`, `json` or `ndjson`"
+                        ));
+                    }
+                };
+            }
+            "--runs" => {
+                let runs: String = value("--runs")?;
+                options.runs = match runs.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => {
+                        return usage_error(format!(
+                            "invalid number of runs `{runs}`, expected a positive number"
+                        ));
+                    }
+                };
+            }
+            "--jobs" | "-j" => {
+                let jobs: String = value("--jobs")?;
+                options.jobs = match jobs.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => {
+                        return usage_error(format!(
+                            "invalid number of jobs `{jobs}`, expected a positive number"
+                        ));
+                    }
+                };
+            }
+            "--quiet" | "-q" => options.quiet = true,
+            // Kept for compatibility with `runner --list`.
+            "--list" => positional.insert(0, "list"),
+            _ if flag.starts_with('-') && flag.len() > 1 => {
+                return usage_error(format!("unknown option `{flag}`"));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let Some(&first) = positional.first() else {
+        return Ok(Cli::Help);
+    };
+
+    // A bare target is shorthand for `run <target>`.
+    let (spec, rest): (&CommandSpec, &[&str]) = match COMMANDS.iter().find(|c| c.name == first) {
+        Some(spec) => (spec, &positional[1..]),
+        None if first.starts_with(|c: char| c.is_ascii_digit()) || first == "all" => {
+            (&COMMANDS[0], &positional[..])
+        }
+        None => return usage_error(format!("unknown command `{first}`")),
+    };
+
+    let target: Option<Target> = match rest {
+        [] if spec.target_required => {
+            return usage_error(format!("`{}` needs a target {}", spec.name, spec.target));
+        }
+        [] => None,
+        [target] => Some(target.parse().map_err(|e: String| UsageError(e))?),
+        [_, extra, ..] => return usage_error(format!("unexpected argument `{extra}`")),
+    };
+
+    // Examples are looked up per day, so any target can run on them.
+    if matches!(options.input, InputSource::File(_) | InputSource::Stdin)
+        && !target.as_ref().is_some_and(Target::is_single_day)
+    {
+        return usage_error("`--input` and `--stdin` need a single day as the target");
+    }
+
+    Ok(Cli::Command {
+        kind: spec.kind,
+        target,
+        options,
+    })
+}
@@ -0,0 +1,251 @@
+use super::{Cli, CommandKind, Format, InputSource, Options, UsageError, help, parse};
+use crate::target::Target;
+use std::path::PathBuf;
+
+fn parse_args(args: &str) -> Result<Cli, UsageError> {
+    let args: Vec<String> = args.split_whitespace().map(str::to_string).collect();
+    parse(&args)
+}
+
+fn command(kind: CommandKind, target: Option<&str>, options: Options) -> Cli {
+    Cli::Command {
+        kind,
+        target: target.map(|t: &str| t.parse::<Target>().unwrap()),
+        options,
+    }
+}
+
+#[test]
+fn parses_subcommands() {
+    assert_eq!(
+        parse_args("run 2025"),
+        Ok(command(CommandKind::Run, Some("2025"), Options::default()))
+    );
+    assert_eq!(
+        parse_args("bench all"),
+        Ok(command(CommandKind::Bench, Some("all"), Options::default()))
+    );
+    assert_eq!(
+        parse_args("bench 2025-08 --runs 50"),
+        Ok(command(
+            CommandKind::Bench,
+            Some("2025-08"),
+            Options {
+                runs: 50,
+                ..Options::default()
+            }
+        ))
+    );
+    assert_eq!(
+        parse_args("watch 2025-10"),
+        Ok(command(
+            CommandKind::Watch,
+            Some("2025-10"),
+            Options::default()
+        ))
+    );
+    assert_eq!(
+        parse_args("puzzle 2025-05"),
+        Ok(command(
+            CommandKind::Puzzle,
+            Some("2025-05"),
+            Options::default()
+        ))
+    );
+    assert_eq!(
+        parse_args("list"),
+        Ok(command(CommandKind::List, None, Options::default()))
+    );
+    assert_eq!(
+        parse_args("verify"),
+        Ok(command(CommandKind::Verify, None, Options::default()))
+    );
+    assert_eq!(
+        parse_args("submit 2025-09 --part 2"),
+        Ok(command(
+            CommandKind::Submit,
+            Some("2025-09"),
+            Options {
+                part: Some(2),
+                ..Options::default()
+            }
+        ))
+    );
+}
+
+#[test]
+fn bare_targets_and_list_flag_still_work() {
+    assert_eq!(
+        parse_args("2025-01"),
+        Ok(command(
+            CommandKind::Run,
+            Some("2025-01"),
+            Options::default()
+        ))
+    );
+    assert_eq!(
+        parse_args("all"),
+        Ok(command(CommandKind::Run, Some("all"), Options::default()))
+    );
+    assert_eq!(
+        parse_args("--list"),
+        Ok(command(CommandKind::List, None, Options::default()))
+    );
+}
+
+#[test]
+fn parses_options_in_any_position() {
+    let expected: Cli = command(
+        CommandKind::Run,
+        Some("2025-05"),
+        Options {
+            input: InputSource::File(PathBuf::from("in.txt")),
+            allow_empty: true,
+            part: Some(1),
+            format: Format::Json,
+            quiet: true,
+            ..Options::default()
+        },
+    );
+    assert_eq!(
+        parse_args("--quiet run --format json 2025-05 --input in.txt --part 1 --allow-empty"),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        parse_args("run 2025-05 --allow-empty --input=in.txt --part=1 --format=json -q"),
+        Ok(expected)
+    );
+    assert_eq!(
+        parse_args("run 2025 --example 2"),
+        Ok(command(
+            CommandKind::Run,
+            Some("2025"),
+            Options {
+                input: InputSource::Example(2),
+                ..Options::default()
+            }
+        ))
+    );
+    assert_eq!(
+        parse_args("run all -j 4"),
+        Ok(command(
+            CommandKind::Run,
+            Some("all"),
+            Options {
+                jobs: Some(4),
+                ..Options::default()
+            }
+        ))
+    );
+    assert_eq!(
+        parse_args("run 2025 --format ndjson"),
+        Ok(command(
+            CommandKind::Run,
+            Some("2025"),
+            Options {
+                format: Format::Ndjson,
+                ..Options::default()
+            }
+        ))
+    );
+    assert_eq!(
+        parse_args("run 2025-05 --stdin"),
+        Ok(command(
+            CommandKind::Run,
+            Some("2025-05"),
+            Options {
+                input: InputSource::Stdin,
+                ..Options::default()
+            }
+        ))
+    );
+}
+
+#[test]
+fn help_is_requested_or_implied() {
+    assert_eq!(parse_args(""), Ok(Cli::Help));
+    assert_eq!(parse_args("--help"), Ok(Cli::Help));
+    assert_eq!(parse_args("run 2025 -h"), Ok(Cli::Help));
+}
+
+#[test]
+fn help_lists_every_command_and_flag() {
+    let text: String = help();
+    for word in [
+        "run",
+        "watch",
+        "bench",
+        "test",
+        "list",
+        "new",
+        "fetch",
+        "puzzle",
+        "submit",
+        "verify",
+        "--input",
+        "--stdin",
+        "--example",
+        "--part",
+        "--allow-empty",
+        "--format",
+        "--jobs",
+        "--runs",
+        "--quiet",
+    ] {
+        assert!(text.contains(word), "help should mention {word}:\n{text}");
+    }
+}
+
+#[test]
+fn reports_usage_errors() {
+    let error = |args: &str| parse_args(args).unwrap_err().0;
+
+    assert_eq!(error("frobnicate"), "unknown command `frobnicate`");
+    assert_eq!(error("run"), "`run` needs a target <target>");
+    assert_eq!(
+        error("run 2025-xx"),
+        "invalid day `xx`, expected a number between 1 and 25"
+    );
+    assert_eq!(error("run 2025 2024"), "unexpected argument `2024`");
+    assert_eq!(error("run 2025 --verbose"), "unknown option `--verbose`");
+    assert_eq!(
+        error("run 2025-01 --part 3"),
+        "invalid part `3`, expected 1 or 2"
+    );
+    assert_eq!(error("run 2025-01 --part"), "`--part` needs a value");
+    assert_eq!(
+        error("run 2025-01 --format xml"),
+        "invalid format `xml`, expected `text`, `json` or `ndjson`"
+    );
+    assert_eq!(
+        error("bench 2025-01 --runs 0"),
+        "invalid number of runs `0`, expected a positive number"
+    );
+    assert_eq!(
+        error("run 2025-01 --input a --stdin"),
+        "`--input` and `--stdin` cannot be combined"
+    );
+    assert_eq!(
+        error("run 2025 --jobs none"),
+        "invalid number of jobs `none`, expected a positive number"
+    );
+    assert_eq!(
+        error("run 2025-01 --stdin --example 1"),
+        "`--stdin` and `--example` cannot be combined"
+    );
+    assert_eq!(
+        error("run 2025-01 --example 0"),
+        "invalid example `0`, expected a positive number"
+    );
+    assert_eq!(
+        error("run 2025 --stdin"),
+        "`--input` and `--stdin` need a single day as the target"
+    );
+}
+
+#[test]
+fn usage_errors_point_to_help() {
+    let message: String = parse_args("run").unwrap_err().to_string();
+    assert!(message.starts_with("error: `run` needs a target"));
+    assert!(message.ends_with("Run `runner --help` for usage."));
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+// Just enough JSON for what the runner writes and reads back itself: arrays of flat
+// objects whose values are strings, non-negative integers, booleans or null.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Number(u64),
+    Bool(bool),
+    Null,
+}
+
+pub type Object = BTreeMap<String, Value>;
+
+pub fn quote(s: &str) -> String {
+    let mut out: String = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn number(fields: &Object, key: &str) -> Result<u64, String> {
+    match fields.get(key) {
+        Some(Value::Number(n)) => Ok(*n),
+        _ => Err(format!("expected a number in `{}`", key)),
+    }
+}
+
+pub fn string<'a>(fields: &'a Object, key: &str) -> Result<&'a str, String> {
+    match fields.get(key) {
+        Some(Value::String(s)) => Ok(s),
+        _ => Err(format!("expected a string in `{}`", key)),
+    }
+}
+
+struct Reader<'a> {
+    rest: &'a str,
+}
+
+impl Reader<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.rest = self.rest.trim_start();
+        self.rest.chars().next()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(())
+            }
+            Some(found) => Err(format!("expected `{}`, found `{}`", c, found)),
+            None => Err(format!("expected `{}`, found the end", c)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut out: String = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(out);
+                }
+                '\\' => match chars.next().map(|(_, c): (usize, char)| c) {
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some(c @ ('"' | '\\' | '/')) => out.push(c),
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        let c: Option<char> =
+                            u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                        match c {
+                            Some(c) if hex.len() == 4 => out.push(c),
+                            _ => return Err(format!("invalid escape `\\u{}`", hex)),
+                        }
+                    }
+                    Some(c) => return Err(format!("unsupported escape `\\{}`", c)),
+                    None => break,
+                },
+                c => out.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        if self.peek() == Some('"') {
+            return self.string().map(Value::String);
+        }
+        for (word, value) in [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ] {
+            if let Some(rest) = self.rest.strip_prefix(word) {
+                self.rest = rest;
+                return Ok(value);
+            }
+        }
+
+        let end: usize = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let number: u64 = self.rest[..end]
+            .parse()
+            .map_err(|_| format!("expected a value at `{:.10}`", self.rest))?;
+        self.rest = &self.rest[end..];
+        Ok(Value::Number(number))
+    }
+
+    fn object(&mut self) -> Result<Object, String> {
+        let mut fields: Object = Object::new();
+        self.expect('{')?;
+        if self.peek() == Some('}') {
+            return self.expect('}').map(|_| fields);
+        }
+        loop {
+            let key: String = self.string()?;
+            self.expect(':')?;
+            fields.insert(key, self.value()?);
+            if self.peek() == Some(',') {
+                self.expect(',')?;
+            } else {
+                self.expect('}')?;
+                return Ok(fields);
+            }
+        }
+    }
+}
+
+pub fn parse_array(text: &str) -> Result<Vec<Object>, String> {
+    let mut reader: Reader = Reader { rest: text };
+    let mut objects: Vec<Object> = Vec::new();
+
+    reader.expect('[')?;
+    if reader.peek() == Some(']') {
+        reader.expect(']')?;
+    } else {
+        loop {
+            objects.push(reader.object()?);
+            if reader.peek() == Some(',') {
+                reader.expect(',')?;
+            } else {
+                reader.expect(']')?;
+                break;
+            }
+        }
+    }
+
+    if let Some(c) = reader.peek() {
+        return Err(format!("unexpected `{}` after the array", c));
+    }
+    Ok(objects)
+}
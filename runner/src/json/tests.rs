@@ -0,0 +1,45 @@
+use super::{Object, Value, number, parse_array, quote, string};
+
+#[test]
+fn quotes_strings() {
+    assert_eq!(
+        quote("a \"b\"\\\n\t\u{1}"),
+        "\"a \\\"b\\\"\\\\\\n\\t\\u0001\""
+    );
+}
+
+#[test]
+fn reads_back_what_it_writes() {
+    let text: String = format!(
+        "[\n  {{\"s\":{},\"n\":42,\"t\":true,\"f\":false,\"x\":null}},\n  {{}}\n]\n",
+        quote("a \"b\"\n\u{1}")
+    );
+    let objects: Vec<Object> = parse_array(&text).unwrap();
+
+    assert_eq!(objects.len(), 2);
+    assert_eq!(string(&objects[0], "s"), Ok("a \"b\"\n\u{1}"));
+    assert_eq!(number(&objects[0], "n"), Ok(42));
+    assert_eq!(objects[0]["t"], Value::Bool(true));
+    assert_eq!(objects[0]["f"], Value::Bool(false));
+    assert_eq!(objects[0]["x"], Value::Null);
+    assert!(objects[1].is_empty());
+    assert_eq!(parse_array(" [ ] ").unwrap(), vec![]);
+}
+
+#[test]
+fn rejects_what_it_does_not_write() {
+    assert!(parse_array("").is_err());
+    assert!(parse_array("{}").is_err());
+    assert!(parse_array("[{\"n\":-1}]").is_err());
+    assert!(parse_array("[{\"n\":1.5}]").is_err());
+    assert!(parse_array("[{\"s\":\"open}]").is_err());
+    assert!(parse_array("[{\"s\":\"\\u12\"}]").is_err());
+    assert!(parse_array("[] []").is_err());
+
+    let objects: Vec<Object> = parse_array("[{\"n\":\"1\"}]").unwrap();
+    assert_eq!(
+        number(&objects[0], "n"),
+        Err("expected a number in `n`".to_string())
+    );
+    assert!(string(&objects[0], "s").is_err());
+}
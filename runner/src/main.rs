@@ -1,62 +1,859 @@
-use aoc_core::Solver;
-use std::env;
-use std::io::Error;
-use std::process;
+mod bench;
+mod cli;
+mod json;
+mod report;
+mod run;
+mod scaffold;
+mod target;
+mod watch;
+mod years;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: runner <year-day>");
-        eprintln!("Example: runner 2025-01");
+use aoc_core::answers::{Answers, Check};
+use aoc_core::config::{CONFIG_FILE, Config};
+use aoc_core::examples::{self, Example};
+use aoc_core::fetch::{self, Client, Fetched};
+use aoc_core::puzzle::{self, Readme, Saved};
+use aoc_core::submit::{self, Ledger, Refusal, Reply, Submission, Verdict};
+use aoc_core::{Day, InputLocator, Registry, SolveError};
+use bench::Measurement;
+use cli::{Cli, CommandKind, Format, InputSource, Options};
+use run::DayRun;
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use std::path::{self, Path, PathBuf};
+use std::process::{self, Command};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs};
+use target::Target;
+use watch::{Outcome, Snapshot};
+
+static REGISTRY: Registry = Registry::new(years::YEARS);
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+fn report_failure(step: &str, e: &SolveError) {
+    eprintln!("{} failed: {}", step, e);
+    if let SolveError::Parse(parse_error) = e {
+        eprintln!("{}", parse_error.snippet());
+    }
+}
+
+fn report_failures(run: &DayRun) {
+    match &run.result {
+        Ok(parts) => {
+            for (i, part) in parts.iter().enumerate() {
+                if let Some(Err(e)) = part
+                    && *e != SolveError::NotImplemented
+                {
+                    report_failure(&format!("Part {}", i + 1), e);
+                }
+            }
+        }
+        Err(e) => report_failure("Parsing", e),
+    }
+}
+
+// Paths inside the current directory are shown relative to it, to keep messages short.
+fn display_path(path: &Path) -> String {
+    env::current_dir()
+        .ok()
+        .and_then(|cwd: PathBuf| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+fn locator() -> InputLocator {
+    InputLocator::from_env().unwrap_or_else(|e: io::Error| fail(e))
+}
+
+fn config(locator: &InputLocator) -> Config {
+    Config::load(&locator.workspace().join(CONFIG_FILE)).unwrap_or_else(|e: io::Error| fail(e))
+}
+
+// A puzzle input and the file it was read from, if any.
+struct Input {
+    text: String,
+    path: Option<PathBuf>,
+}
+
+// Loads the input of one day. Missing or blank input is an error unless `--allow-empty`
+// is given, as most solvers happily turn an empty string into plausible-looking zeros.
+fn load_input(day: &Day, options: &Options, locator: &InputLocator) -> Result<Input, String> {
+    let (text, path): (String, Option<PathBuf>) = match &options.input {
+        InputSource::Default => match locator.find(day.year, day.day) {
+            Some(path) => (
+                fs::read_to_string(&path).map_err(|e: io::Error| {
+                    format!("cannot read {}: {}", display_path(&path), e)
+                })?,
+                Some(path),
+            ),
+            None if options.allow_empty => {
+                return Ok(Input {
+                    text: String::new(),
+                    path: None,
+                });
+            }
+            None => {
+                return Err(format!(
+                    "no input file for {}-{:02}\nhint: save your puzzle input as {} (or pass --allow-empty to run without it)",
+                    day.year,
+                    day.day,
+                    display_path(&locator.default_path(day.year, day.day))
+                ));
+            }
+        },
+        InputSource::File(path) => (
+            fs::read_to_string(path)
+                .map_err(|e: io::Error| format!("cannot read {}: {}", path.display(), e))?,
+            Some(path.clone()),
+        ),
+        InputSource::Example(n) => {
+            let dir: PathBuf = locator.day_dir(day.year, day.day);
+            let path: PathBuf = puzzle::example_path(&dir, *n);
+            let text: String = fs::read_to_string(&path).map_err(|e: io::Error| {
+                if e.kind() == io::ErrorKind::NotFound {
+                    format!(
+                        "{}-{:02} has no example {}\nhint: `puzzle {}-{:02}` saves the examples of a day in {}",
+                        day.year,
+                        day.day,
+                        n,
+                        day.year,
+                        day.day,
+                        display_path(&dir)
+                    )
+                } else {
+                    format!("cannot read {}: {}", display_path(&path), e)
+                }
+            })?;
+            (text, Some(path))
+        }
+        InputSource::Stdin => {
+            let mut text: String = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|e: io::Error| format!("cannot read standard input: {}", e))?;
+            (text, None)
+        }
+    };
+
+    if text.trim().is_empty() && !options.allow_empty {
+        let origin: String = path
+            .as_deref()
+            .map_or("standard input".to_string(), display_path);
+        return Err(format!(
+            "the input for {}-{:02} in {} is empty\nhint: paste your puzzle input into it (or pass --allow-empty to run anyway)",
+            day.year, day.day, origin
+        ));
+    }
+    Ok(Input { text, path })
+}
+
+fn select(target: &Target) -> Vec<&'static Day> {
+    target.select(&REGISTRY).unwrap_or_else(|e: String| fail(e))
+}
+
+fn print_single_day(run: &DayRun, options: &Options) {
+    if !options.quiet {
+        println!("--- Year {} Day {} ---", run.day.year, run.day.day);
+        if run.day.solver.parses() {
+            println!("Parse: {}", report::format_duration(run.parse_time));
+        }
+    }
+
+    let Ok(parts) = &run.result else {
+        return;
+    };
+    for (i, (part, time)) in parts.iter().zip(run.part_times).enumerate() {
+        let time: String = time.map(report::format_duration).unwrap_or_default();
+        match part {
+            Some(Ok(answer)) if options.quiet => println!("{}", answer),
+            Some(Ok(answer)) if answer.is_multiline() => {
+                println!("Part {} ({}):\n{}", i + 1, time, answer)
+            }
+            Some(Ok(answer)) => println!("Part {}: {} ({})", i + 1, answer, time),
+            Some(Err(SolveError::NotImplemented)) if !options.quiet => {
+                println!("Part {}: not implemented", i + 1)
+            }
+            _ => {}
+        }
+    }
+}
+
+// All inputs are checked before anything runs, so every missing one is reported at once.
+// With `skip_unimplemented`, a day without any solution yet does not need its input: it
+// is skipped rather than failing a run over a whole year.
+fn load_inputs(
+    days: &[&'static Day],
+    options: &Options,
+    locator: &InputLocator,
+    skip_unimplemented: bool,
+) -> Vec<Input> {
+    let mut inputs: Vec<Input> = Vec::with_capacity(days.len());
+    let mut missing: usize = 0;
+    for day in days {
+        match load_input(day, options, locator) {
+            Ok(input) => inputs.push(input),
+            Err(_) if skip_unimplemented && !day.solver.implemented() => {
+                if !options.quiet {
+                    eprintln!(
+                        "note: skipping {}-{:02}, which has no input and no solution yet",
+                        day.year, day.day
+                    );
+                }
+                inputs.push(Input {
+                    text: String::new(),
+                    path: None,
+                });
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                missing += 1;
+            }
+        }
+    }
+    if missing > 0 {
         process::exit(1);
     }
+    inputs
+}
 
-    let target: &String = &args[1];
-    let parts: Vec<&str> = target.split('-').collect();
+fn run_command(target: &Target, options: &Options) {
+    let days: Vec<&'static Day> = select(target);
+    let inputs: Vec<Input> = load_inputs(&days, options, &locator(), !target.is_single_day());
+
+    let jobs: usize = options
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n: NonZeroUsize| n.get()));
+    let work: Vec<(&'static Day, &str)> = days
+        .iter()
+        .zip(&inputs)
+        .map(|(&day, input): (&&'static Day, &Input)| (day, input.text.as_str()))
+        .collect();
+    let runs: Vec<DayRun> = run::run_days(&work, options.part, jobs);
+
+    // Failures are reported once everything ran, in the order of the days.
+    for run in runs.iter().filter(|r: &&DayRun| r.failed()) {
+        if !target.is_single_day() {
+            eprintln!("--- Year {} Day {} ---", run.day.year, run.day.day);
+        }
+        report_failures(run);
+    }
+
+    match options.format {
+        Format::Json | Format::Ndjson => {
+            let records: Vec<String> = runs
+                .iter()
+                .zip(&inputs)
+                .flat_map(|(run, input): (&DayRun, &Input)| {
+                    report::json_records(run, input.path.as_deref())
+                })
+                .collect();
+            print!("{}", report::json_output(&records, options.format));
+        }
+        Format::Text if target.is_single_day() => print_single_day(&runs[0], options),
+        Format::Text if options.quiet => {
+            for part in runs
+                .iter()
+                .filter_map(|r: &DayRun| r.result.as_ref().ok())
+                .flatten()
+            {
+                if let Some(Ok(answer)) = part {
+                    println!("{}", answer);
+                }
+            }
+        }
+        Format::Text => print!("{}", report::summary_table(&runs)),
+    }
 
-    if parts.len() != 2 {
-        eprintln!("Invalid format. Use <year-day>, e.g., 2025-01");
+    if runs.iter().any(DayRun::failed) {
         process::exit(1);
     }
+}
+
+// The answer a part should give on the input it runs on, if that is known: the confirmed
+// one for the puzzle input, or the expected one for an example.
+fn known_answer(
+    locator: &InputLocator,
+    input: &InputSource,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Option<String> {
+    match input {
+        InputSource::Default => Answers::load(Answers::path_for(locator, year))
+            .ok()?
+            .get(day, part)
+            .map(str::to_string),
+        InputSource::Example(n) => {
+            let dir: PathBuf = locator.day_dir(year, day);
+            let path: PathBuf = puzzle::example_path(&dir, *n);
+            examples::load(&dir)
+                .ok()?
+                .into_iter()
+                .find(|e: &Example| e.input == path)?
+                .expected[usize::from(part) - 1]
+                .clone()
+        }
+        InputSource::File(_) | InputSource::Stdin => None,
+    }
+}
+
+// Runs the selected days in a child runner, built by cargo, whenever a file they depend
+// on changes: their sources and examples, their inputs, the shared aoc-core sources and
+// the confirmed answers. Each run is shown next to the previous one.
+fn watch_command(target: &Target, options: &Options) {
+    let days: Vec<&'static Day> = select(target);
+    let locator: InputLocator = locator();
+
+    let mut run_args: Vec<String> = vec![
+        "run".to_string(),
+        target.to_string(),
+        "--format".to_string(),
+        "json".to_string(),
+    ];
+    match &options.input {
+        InputSource::Default => {}
+        // The runs start in the workspace, so a relative path would point elsewhere.
+        InputSource::File(path) => {
+            let path: PathBuf = path::absolute(path)
+                .unwrap_or_else(|e: io::Error| fail(format!("{}: {}", path.display(), e)));
+            run_args.extend(["--input".to_string(), path.display().to_string()])
+        }
+        InputSource::Stdin => fail("`watch` cannot read standard input again after every change"),
+        InputSource::Example(n) => run_args.extend(["--example".to_string(), n.to_string()]),
+    }
+    if let Some(part) = options.part {
+        run_args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(jobs) = options.jobs {
+        run_args.extend(["--jobs".to_string(), jobs.to_string()]);
+    }
+    if options.allow_empty {
+        run_args.push("--allow-empty".to_string());
+    }
+
+    let mut watched: Vec<PathBuf> = vec![locator.workspace().join("aoc-core").join("src")];
+    for day in &days {
+        let year_dir: PathBuf = locator.workspace().join(format!("year{}", day.year));
+        watched.push(year_dir.join("src").join("lib.rs"));
+        watched.push(locator.day_dir(day.year, day.day));
+        watched.push(match &options.input {
+            InputSource::File(path) => path.clone(),
+            InputSource::Default => locator
+                .find(day.year, day.day)
+                .unwrap_or_else(|| locator.default_path(day.year, day.day)),
+            _ => continue,
+        });
+        watched.push(Answers::path_for(&locator, day.year));
+    }
+    watched.sort();
+    watched.dedup();
+
+    let mut previous: Vec<Outcome> = Vec::new();
+    let mut files: Snapshot = watch::snapshot(&watched);
+    let mut changed: Vec<PathBuf> = Vec::new();
+    loop {
+        if let Some(first) = changed.first() {
+            let more: String = match changed.len() {
+                1 => String::new(),
+                n => format!(" and {} more", n - 1),
+            };
+            println!("--- {}{} changed ---", display_path(first), more);
+        }
+
+        let mut cargo: Command =
+            Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        cargo.args(["run", "--quiet", "-p", "runner"]);
+        if !cfg!(debug_assertions) {
+            cargo.arg("--release");
+        }
+        // A target directory of its own, so the build never replaces this very runner.
+        let target_dir: PathBuf = env::var_os("CARGO_TARGET_DIR")
+            .map_or_else(|| locator.workspace().join("target"), PathBuf::from)
+            .join("watch");
+        cargo
+            .env("CARGO_TARGET_DIR", target_dir)
+            .arg("--")
+            .args(&run_args)
+            .current_dir(locator.workspace());
+        let output: process::Output = cargo
+            .stderr(process::Stdio::inherit())
+            .output()
+            .unwrap_or_else(|e: io::Error| fail(format!("cannot run cargo: {}", e)));
+
+        match watch::parse_outcomes(&String::from_utf8_lossy(&output.stdout)) {
+            Ok(outcomes) => {
+                let known = |year: u16, day: u8, part: u8| -> Option<String> {
+                    known_answer(&locator, &options.input, year, day, part)
+                };
+                print!("{}", watch::table(&outcomes, &previous, known));
+                previous = outcomes;
+            }
+            Err(_) => println!("nothing to show, the build or the run failed"),
+        }
+        println!("watching {} files, press Ctrl-C to stop", files.len());
+
+        loop {
+            thread::sleep(watch::POLL);
+            let next: Snapshot = watch::snapshot(&watched);
+            changed = watch::changes(&files, &next);
+            files = next;
+            if !changed.is_empty() {
+                break;
+            }
+        }
+        // Editors and formatters tend to write files in several steps; let them finish.
+        thread::sleep(watch::POLL);
+        files = watch::snapshot(&watched);
+    }
+}
+
+// Times the selected days and compares them with the last results, which they then
+// replace. Debug builds are too slow to compare with anything, so their timings are
+// only shown.
+fn bench_command(target: &Target, options: &Options) {
+    let days: Vec<&'static Day> = select(target);
+    let locator: InputLocator = locator();
+    let inputs: Vec<Input> = load_inputs(&days, options, &locator, !target.is_single_day());
+
+    let release: bool = !cfg!(debug_assertions);
+    if !release {
+        eprintln!(
+            "warning: this is a debug build, so the results are neither compared nor saved\nhint: use `cargo run --release -p runner -- bench ...`"
+        );
+    }
+    let path: PathBuf = locator.workspace().join(bench::BENCH_FILE);
+    let last: Vec<Measurement> = if release {
+        bench::load(&path).unwrap_or_else(|e: io::Error| fail(e))
+    } else {
+        Vec::new()
+    };
+
+    let mut measurements: Vec<Measurement> = Vec::new();
+    let mut failed: bool = false;
+    for (day, input) in days.iter().zip(&inputs) {
+        // A first run checks that everything works before it is timed.
+        let run: DayRun = run::run_day(day, &input.text, options.part);
+        if run.failed() {
+            eprintln!("--- Year {} Day {} ---", day.year, day.day);
+            report_failures(&run);
+            failed = true;
+            continue;
+        }
+        let Ok(parts) = &run.result else {
+            continue;
+        };
+        let solved: [bool; 2] = [0, 1].map(|i: usize| matches!(parts[i], Some(Ok(_))));
+        match bench::bench_day(day, &input.text, solved, options.runs) {
+            Ok(measured) => measurements.extend(measured),
+            Err(e) => {
+                report_failure(&format!("{}-{:02}", day.year, day.day), &e);
+                failed = true;
+            }
+        }
+    }
 
-    let year: u16 = parts[0].parse().expect("Invalid year");
-    let day: u8 = parts[1].parse().expect("Invalid day");
+    match options.format {
+        Format::Json | Format::Ndjson => print!(
+            "{}",
+            report::json_output(&bench::json_records(&measurements), options.format)
+        ),
+        Format::Text => print!(
+            "{}\n{}",
+            bench::phase_table(&days, &measurements, &last),
+            bench::year_table(&measurements)
+        ),
+    }
 
-    let solver: Option<Box<dyn Solver>> = match year {
-        2025 => year2025::get_solver(day),
-        _ => {
-            eprintln!("Year {} not implemented", year);
-            process::exit(1);
+    if release {
+        let regressions: usize = bench::regressions(&measurements, &last);
+        if regressions > 0 {
+            eprintln!(
+                "{} of {} phases got slower since the last run",
+                regressions,
+                measurements.len()
+            );
         }
+        fs::write(&path, bench::to_json(&bench::merge(&last, &measurements))).unwrap_or_else(
+            |e: io::Error| fail(format!("cannot save {}: {}", display_path(&path), e)),
+        );
+        if !options.quiet {
+            eprintln!("results saved to {}", display_path(&path));
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn list_command(target: Option<&Target>) {
+    let days: Vec<&'static Day> = match target {
+        Some(target) => select(target),
+        None => REGISTRY.days().collect(),
     };
+    for day in days {
+        println!("{}-{:02}  {}", day.year, day.day, day.name);
+    }
+}
 
-    if let Some(solver) = solver {
-        // In a real scenario, we would read the input file here.
-        // For now, we'll pass a dummy string or try to read it if it exists.
-        // The input file path convention could be `year{}/src/day{:02}/input.txt` but that's inside src.
-        // Better: `year{}/input/day{:02}.txt` or similar.
-        // For this basic setup, let's assume the input is passed or hardcoded for now,
-        // or we try to read from `year2025/src/day01/input.txt` relative to CWD.
+// Runs the unit tests of the selected days through cargo, one year crate at a time.
+fn test_command(target: &Target, options: &Options) {
+    let days: Vec<&'static Day> = select(target);
 
-        // Use the generic input reader
-        let input: String = aoc_core::read_input(year, day).unwrap_or_else(|e: Error| {
-            eprintln!("Error reading input: {}", e);
-            String::new()
-        });
+    let mut years: Vec<u16> = days.iter().map(|d: &&Day| d.year).collect();
+    years.dedup();
+
+    for year in years {
+        let mut cargo: Command =
+            Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        cargo.args(["test", "-p", &format!("year{}", year)]);
+        if options.quiet {
+            cargo.arg("--quiet");
+        }
+        if let Target::Days { .. } = target {
+            cargo.arg("--");
+            cargo.args(
+                days.iter()
+                    .filter(|d: &&&Day| d.year == year)
+                    .map(|d: &&Day| format!("day{:02}::", d.day)),
+            );
+        }
+
+        let status: process::ExitStatus = cargo
+            .status()
+            .unwrap_or_else(|e: io::Error| fail(format!("cannot run cargo: {}", e)));
+        if !status.success() {
+            process::exit(status.code().unwrap_or(1));
+        }
+    }
+}
+
+// Downloads the inputs of the selected puzzles that are not on disk yet.
+fn fetch_command(target: &Target, options: &Options) {
+    let puzzles: Vec<(u16, u8)> = target
+        .puzzles(&REGISTRY)
+        .unwrap_or_else(|e: String| fail(e));
+    let locator: InputLocator = locator();
+    let client: Client = Client::from_env(&config(&locator));
+
+    let mut failed: bool = false;
+    for (year, day) in puzzles {
+        match fetch::fetch_input(&client, &locator, year, day) {
+            Ok(Fetched::Cached(path)) if !options.quiet => {
+                println!("{}-{:02}: already at {}", year, day, display_path(&path))
+            }
+            Ok(Fetched::Cached(_)) => {}
+            Ok(Fetched::Downloaded(path)) => {
+                println!("{}-{:02}: saved to {}", year, day, display_path(&path))
+            }
+            Err(e) => {
+                eprintln!("error: {}-{:02}: {}", year, day, e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
 
-        println!("--- Year {} Day {} ---", year, day);
+// Downloads the descriptions and examples of the selected days into their directories,
+// and names days that were created with a placeholder name.
+fn puzzle_command(target: &Target, options: &Options) {
+    let days: Vec<&'static Day> = select(target);
+    let locator: InputLocator = locator();
+    let client: Client = Client::from_env(&config(&locator));
 
-        let results: Vec<String> = solver.solve(&input);
+    let mut failed: bool = false;
+    for day in days {
+        let dir: PathBuf = locator.day_dir(day.year, day.day);
+        let saved: Saved = match puzzle::fetch_puzzle(&client, &dir, day.year, day.day) {
+            Ok(saved) => saved,
+            Err(e) => {
+                eprintln!("error: {}-{:02}: {}", day.year, day.day, e);
+                failed = true;
+                continue;
+            }
+        };
 
-        if results.is_empty() {
-            println!("No parts implemented.");
+        let readme: &str = match saved.readme {
+            Readme::Complete if options.quiet => continue,
+            Readme::Complete => "already has both parts",
+            Readme::Created => "created",
+            Readme::Updated => "updated",
+            Readme::Unchanged => "unchanged",
+        };
+        let pending: &str = if saved.part_two {
+            ""
         } else {
-            for (i, result) in results.iter().enumerate() {
-                println!("Part {}: {}", i + 1, result);
+            " (Part Two is not unlocked yet)"
+        };
+        println!(
+            "{}-{:02}: {} {}{}",
+            day.year,
+            day.day,
+            display_path(&dir.join(puzzle::README_FILE)),
+            readme,
+            pending
+        );
+        for path in &saved.examples {
+            println!("{}-{:02}: saved {}", day.year, day.day, display_path(path));
+        }
+
+        if let Some(title) = &saved.title
+            && day.name == scaffold::UNNAMED
+        {
+            let lib: PathBuf = locator
+                .workspace()
+                .join(format!("year{}", day.year))
+                .join("src")
+                .join("lib.rs");
+            let renamed: Result<(), io::Error> =
+                fs::read_to_string(&lib).and_then(|text: String| {
+                    fs::write(&lib, scaffold::rename_day(&text, day.day, title))
+                });
+            match renamed {
+                Ok(()) => println!("{}-{:02}: named {:?}", day.year, day.day, title),
+                Err(e) => eprintln!("error: cannot name {}-{:02}: {}", day.year, day.day, e),
             }
         }
-    } else {
-        eprintln!("Day {} not implemented for year {}", day, year);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// Solves one part and submits its answer, unless the ledger of earlier submissions
+// already shows that it cannot be right or that the site wants us to wait.
+fn submit_command(target: &Target, options: &Options) {
+    let (Some(part), true) = (options.part, target.is_single_day()) else {
+        fail("`submit` needs a single day and `--part 1` or `--part 2`");
+    };
+    if let InputSource::Example(_) = options.input {
+        fail("`submit` needs the puzzle input, not an example");
+    }
+    let day: &'static Day = select(target)[0];
+    let locator: InputLocator = locator();
+
+    let input: Input = load_input(day, options, &locator).unwrap_or_else(|e: String| fail(e));
+    let run: DayRun = run::run_day(day, &input.text, Some(part));
+    report_failures(&run);
+    let answer: String = match &run.result {
+        Ok(parts) => match &parts[usize::from(part) - 1] {
+            Some(Ok(answer)) if answer.is_multiline() => {
+                fail("multi-line answers have to be read and submitted by hand")
+            }
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(SolveError::NotImplemented)) => {
+                fail(format!("part {} is not implemented", part))
+            }
+            _ => process::exit(1),
+        },
+        Err(_) => process::exit(1),
+    };
+
+    let mut answers: Answers =
+        Answers::load(Answers::path_for(&locator, day.year)).unwrap_or_else(|e: io::Error| fail(e));
+    if let Some(known) = answers.get(day.day, part) {
+        fail(format!(
+            "not submitting {}: {}",
+            answer,
+            Refusal::AlreadyCorrect(known.to_string())
+        ));
+    }
+
+    let mut ledger: Ledger = Ledger::load(Ledger::path_for(&locator, day.year))
+        .unwrap_or_else(|e: io::Error| fail(e));
+    let now: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    if let Err(refusal) = ledger.check(day.year, day.day, part, &answer, now) {
+        fail(format!("not submitting {}: {}", answer, refusal));
+    }
+
+    let client: Client = Client::from_env(&config(&locator));
+    let reply: Reply = submit::submit(&client, day.year, day.day, part, &answer)
+        .unwrap_or_else(|e: fetch::FetchError| fail(e));
+    ledger
+        .record(Submission {
+            year: day.year,
+            day: day.day,
+            part,
+            time: now,
+            verdict: reply.verdict.clone(),
+            wait: reply.wait,
+            answer: answer.clone(),
+        })
+        .unwrap_or_else(|e: io::Error| fail(format!("cannot record the submission: {}", e)));
+
+    match reply.wait {
+        Some(wait) => println!(
+            "{}: {} (wait {}s before the next answer)",
+            answer, reply.verdict, wait
+        ),
+        None => println!("{}: {}", answer, reply.verdict),
+    }
+    if reply.verdict != Verdict::Correct {
+        process::exit(1);
+    }
+
+    answers.set(day.day, part, &answer);
+    answers
+        .save()
+        .unwrap_or_else(|e: io::Error| fail(format!("cannot record the answer: {}", e)));
+    println!("recorded in {}", display_path(answers.path()));
+}
+
+// Re-solves the selected days and compares their answers with the confirmed ones.
+// Days without any confirmed answer are skipped, so they need no input.
+fn verify_command(target: Option<&Target>, options: &Options) {
+    if let InputSource::Example(_) = options.input {
+        fail("`verify` checks the answers to the puzzle inputs, not to the examples");
+    }
+    let days: Vec<&'static Day> = match target {
+        Some(target) => select(target),
+        None => REGISTRY.days().collect(),
+    };
+    let locator: InputLocator = locator();
+
+    let (mut correct, mut wrong, mut unknown): (usize, usize, usize) = (0, 0, 0);
+    let mut years: BTreeMap<u16, Answers> = BTreeMap::new();
+    for day in days {
+        let answers: &Answers = years.entry(day.year).or_insert_with(|| {
+            Answers::load(Answers::path_for(&locator, day.year))
+                .unwrap_or_else(|e: io::Error| fail(e))
+        });
+
+        if !answers.has_day(day.day) {
+            if !options.quiet {
+                println!("{}-{:02}: no confirmed answers", day.year, day.day);
+            }
+            unknown += 2;
+            continue;
+        }
+        let input: Input = match load_input(day, options, &locator) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                wrong += 2;
+                continue;
+            }
+        };
+
+        let run: DayRun = run::run_day(day, &input.text, None);
+        report_failures(&run);
+        let Ok(parts) = &run.result else {
+            wrong += 2;
+            continue;
+        };
+        for (i, part) in parts.iter().enumerate() {
+            let number: u8 = i as u8 + 1;
+            let status: String = match part {
+                Some(Ok(answer)) => match answers.check(day.day, number, answer) {
+                    Check::Correct => {
+                        correct += 1;
+                        if options.quiet {
+                            continue;
+                        }
+                        "ok".to_string()
+                    }
+                    Check::Wrong(expected) => {
+                        wrong += 1;
+                        format!("WRONG, expected {}, got {}", expected, answer)
+                    }
+                    Check::Unknown => {
+                        unknown += 1;
+                        if options.quiet {
+                            continue;
+                        }
+                        format!("{} is not confirmed yet", answer)
+                    }
+                },
+                _ if answers.get(day.day, number).is_none() => {
+                    unknown += 1;
+                    continue;
+                }
+                _ => {
+                    wrong += 1;
+                    "WRONG, no answer".to_string()
+                }
+            };
+            println!("{}-{:02} part {}: {}", day.year, day.day, number, status);
+        }
+    }
+
+    println!(
+        "{} correct, {} wrong, {} without a confirmed answer",
+        correct, wrong, unknown
+    );
+    if wrong > 0 {
         process::exit(1);
     }
 }
+
+// Creates the files of a new day from the templates and registers it, creating the
+// year crate as well if needed.
+fn new_command(target: &Target) {
+    let Target::Days { year, days } = target else {
+        fail("`new` needs a single day, e.g. `new 2025-13`");
+    };
+    let [day] = days[..] else {
+        fail("`new` needs a single day, e.g. `new 2025-13`");
+    };
+    if REGISTRY.get(*year, day).is_some() {
+        fail(format!("{}-{:02} already exists", year, day));
+    }
+
+    let locator: InputLocator = locator();
+    let touched: Vec<PathBuf> =
+        scaffold::new_day(locator.workspace(), *year, day).unwrap_or_else(|e: String| fail(e));
+    for path in touched {
+        println!("{}", display_path(&path));
+    }
+    println!(
+        "hint: `puzzle {}-{:02}` and `fetch {}-{:02}` download its description and input",
+        year, day, year, day
+    );
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let cli: Cli = cli::parse(&args).unwrap_or_else(|e: cli::UsageError| {
+        eprintln!("{}", e);
+        process::exit(2);
+    });
+
+    let Cli::Command {
+        kind,
+        target,
+        options,
+    } = cli
+    else {
+        print!("{}", cli::help());
+        return;
+    };
+
+    match (kind, &target) {
+        (CommandKind::List, target) => list_command(target.as_ref()),
+        (CommandKind::Verify, target) => verify_command(target.as_ref(), &options),
+        (CommandKind::Run, Some(target)) => run_command(target, &options),
+        (CommandKind::Test, Some(target)) => test_command(target, &options),
+        (CommandKind::Fetch, Some(target)) => fetch_command(target, &options),
+        (CommandKind::Puzzle, Some(target)) => puzzle_command(target, &options),
+        (CommandKind::Submit, Some(target)) => submit_command(target, &options),
+        (CommandKind::New, Some(target)) => new_command(target),
+        (CommandKind::Watch, Some(target)) => watch_command(target, &options),
+        (CommandKind::Bench, Some(target)) => bench_command(target, &options),
+        // The parser insists on a target for every other command.
+        (_, None) => unreachable!(),
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests;
+
+use crate::cli::Format;
+use crate::json;
+use crate::run::{DayRun, PartResult};
+use aoc_core::SolveError;
+use std::path::Path;
+use std::time::Duration;
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos: u128 = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+fn answer_cell(part: &PartResult, time: Option<Duration>) -> String {
+    let answer: String = match part {
+        None => return String::new(),
+        Some(Ok(answer)) if answer.is_multiline() => "(multi-line)".to_string(),
+        Some(Ok(answer)) => answer.to_string(),
+        Some(Err(SolveError::NotImplemented)) => return "-".to_string(),
+        Some(Err(SolveError::Panicked(_))) => "panicked".to_string(),
+        Some(Err(_)) => "error".to_string(),
+    };
+    match time {
+        Some(time) => format!("{} ({})", answer, format_duration(time)),
+        None => answer,
+    }
+}
+
+// Renders rows as columns padded to their widest cell. Columns marked in `right` are
+// right-aligned, which suits numbers and durations.
+pub fn table(rows: &[Vec<String>], right: &[bool]) -> String {
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        widths.resize(widths.len().max(row.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out: String = String::new();
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width)): (usize, (&String, &usize))| {
+                if right.get(i).copied().unwrap_or(false) {
+                    format!("{:>width$}", cell)
+                } else {
+                    format!("{:<width$}", cell)
+                }
+            })
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+// One row per day plus a total, each answer with the time its part took. Answers of
+// failed parts only say that they failed; the details are reported while running.
+pub fn summary_table(runs: &[DayRun]) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec![
+        "Day".to_string(),
+        "Name".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+    ]];
+
+    for run in runs {
+        let (part1, part2): (String, String) = match &run.result {
+            Ok([part1, part2]) => (
+                answer_cell(part1, run.part_times[0]),
+                answer_cell(part2, run.part_times[1]),
+            ),
+            Err(_) => ("parse error".to_string(), "parse error".to_string()),
+        };
+        let parse: String = if run.day.solver.parses() {
+            format_duration(run.parse_time)
+        } else {
+            String::new()
+        };
+        rows.push(vec![
+            format!("{}-{:02}", run.day.year, run.day.day),
+            run.day.name.to_string(),
+            parse,
+            part1,
+            part2,
+            format_duration(run.elapsed()),
+        ]);
+    }
+
+    let total: Duration = runs.iter().map(DayRun::elapsed).sum();
+    rows.push(vec![
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total),
+    ]);
+
+    table(&rows, &[false, false, true, false, false, true])
+}
+
+// The JSON objects describing one day: one for every part that ran, holding either
+// its answer or its error and how long it took. A day that failed to parse gets a
+// single record without a part. `input` is the file the input was read from.
+pub fn json_records(run: &DayRun, input: Option<&Path>) -> Vec<String> {
+    let prefix: String = format!(
+        "{{\"year\":{},\"day\":{},\"name\":{}",
+        run.day.year,
+        run.day.day,
+        json::quote(run.day.name)
+    );
+    let input: String = input.map_or("null".to_string(), |path: &Path| {
+        json::quote(&path.display().to_string())
+    });
+    let outcome = |result: Result<String, &SolveError>, time: Duration| -> String {
+        let field: String = match result {
+            Ok(answer) => format!("\"ok\":true,\"answer\":{}", json::quote(&answer)),
+            Err(e) => format!("\"ok\":false,\"error\":{}", json::quote(&e.to_string())),
+        };
+        format!(
+            "\"input\":{},{},\"time_ns\":{}}}",
+            input,
+            field,
+            time.as_nanos()
+        )
+    };
+
+    match &run.result {
+        Ok(parts) => parts
+            .iter()
+            .zip(run.part_times)
+            .enumerate()
+            .filter_map(
+                |(i, (part, time)): (usize, (&PartResult, Option<Duration>))| {
+                    let result: Result<String, &SolveError> = match part.as_ref()? {
+                        Ok(answer) => Ok(answer.to_string()),
+                        Err(e) => Err(e),
+                    };
+                    Some(format!(
+                        "{},\"part\":{},{}",
+                        prefix,
+                        i + 1,
+                        outcome(result, time.unwrap_or_default())
+                    ))
+                },
+            )
+            .collect(),
+        Err(e) => vec![format!("{},{}", prefix, outcome(Err(e), run.parse_time))],
+    }
+}
+
+// A JSON array with one record per line, or for `ndjson`, just the records, one per line.
+pub fn json_output(records: &[String], format: Format) -> String {
+    if format == Format::Ndjson {
+        return records
+            .iter()
+            .map(|r: &String| format!("{}\n", r))
+            .collect();
+    }
+    if records.is_empty() {
+        return "[]\n".to_string();
+    }
+    let lines: Vec<String> = records
+        .iter()
+        .map(|r: &String| format!("  {}", r))
+        .collect();
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
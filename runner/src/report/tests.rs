@@ -0,0 +1,126 @@
+use super::{format_duration, json_output, json_records, summary_table};
+use crate::cli::Format;
+use crate::run::DayRun;
+use aoc_core::{Answer, Day, SolveError, Solver};
+use std::path::Path;
+use std::time::Duration;
+
+struct Unused;
+
+impl Solver for Unused {
+    type Parsed = Vec<u8>;
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(Vec::new())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+}
+
+static FIRST: Day = Day {
+    year: 2025,
+    day: 1,
+    name: "Secret Entrance",
+    solver: &Unused,
+};
+
+static SECOND: Day = Day {
+    year: 2025,
+    day: 2,
+    name: "Gift Shop",
+    solver: &Unused,
+};
+
+#[test]
+fn durations_use_a_readable_unit() {
+    assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+    assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3 µs");
+    assert_eq!(format_duration(Duration::from_micros(3_210)), "3.2 ms");
+    assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50 s");
+}
+
+#[test]
+fn summary_table_lines_up_columns() {
+    let runs: Vec<DayRun> = vec![
+        DayRun {
+            day: &FIRST,
+            result: Ok([
+                Some(Ok(Answer::from(1234))),
+                Some(Err(SolveError::NotImplemented)),
+            ]),
+            parse_time: Duration::from_micros(200),
+            part_times: [
+                Some(Duration::from_micros(1_200)),
+                Some(Duration::from_nanos(100)),
+            ],
+        },
+        DayRun {
+            day: &SECOND,
+            result: Err(SolveError::InvalidInput("bad".to_string())),
+            parse_time: Duration::from_micros(500),
+            part_times: [None, None],
+        },
+    ];
+
+    let expected: &str = "\
+Day      Name                Parse  Part 1         Part 2           Time
+2025-01  Secret Entrance  200.0 µs  1234 (1.2 ms)  -              1.4 ms
+2025-02  Gift Shop        500.0 µs  parse error    parse error  500.0 µs
+Total                                                             1.9 ms
+";
+    assert_eq!(summary_table(&runs), expected);
+}
+
+#[test]
+fn json_records_have_one_record_per_part() {
+    let run: DayRun = DayRun {
+        day: &FIRST,
+        result: Ok([
+            Some(Ok(Answer::from("a \"quoted\"\nanswer"))),
+            Some(Err(SolveError::NotImplemented)),
+        ]),
+        parse_time: Duration::ZERO,
+        part_times: [Some(Duration::from_nanos(1_500)), Some(Duration::ZERO)],
+    };
+    assert_eq!(
+        json_records(&run, Some(Path::new("inputs/2025/day01.in"))),
+        vec![
+            "{\"year\":2025,\"day\":1,\"name\":\"Secret Entrance\",\"part\":1,\"input\":\"inputs/2025/day01.in\",\"ok\":true,\"answer\":\"a \\\"quoted\\\"\\nanswer\",\"time_ns\":1500}",
+            "{\"year\":2025,\"day\":1,\"name\":\"Secret Entrance\",\"part\":2,\"input\":\"inputs/2025/day01.in\",\"ok\":false,\"error\":\"not implemented\",\"time_ns\":0}",
+        ]
+    );
+
+    let run: DayRun = DayRun {
+        day: &SECOND,
+        result: Err(SolveError::InvalidInput("bad".to_string())),
+        parse_time: Duration::from_micros(2),
+        part_times: [None, None],
+    };
+    assert_eq!(
+        json_records(&run, None),
+        vec![
+            "{\"year\":2025,\"day\":2,\"name\":\"Gift Shop\",\"input\":null,\"ok\":false,\"error\":\"invalid input: bad\",\"time_ns\":2000}"
+        ]
+    );
+}
+
+#[test]
+fn json_output_is_an_array_or_one_record_per_line() {
+    let records: Vec<String> = vec!["{\"a\":1}".to_string(), "{\"b\":2}".to_string()];
+    assert_eq!(
+        json_output(&records, Format::Json),
+        "[\n  {\"a\":1},\n  {\"b\":2}\n]\n"
+    );
+    assert_eq!(
+        json_output(&records, Format::Ndjson),
+        "{\"a\":1}\n{\"b\":2}\n"
+    );
+    assert_eq!(json_output(&[], Format::Json), "[]\n");
+    assert_eq!(json_output(&[], Format::Ndjson), "");
+}
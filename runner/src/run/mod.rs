@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests;
+
+use aoc_core::{Answer, Day, Prepared, SolveError};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, ScopedJoinHandle};
+use std::time::{Duration, Instant};
+
+// The result of one part, or `None` if that part was not asked for.
+pub type PartResult = Option<Result<Answer, SolveError>>;
+
+// The outcome of running one day. The outer error means the input did not parse,
+// in which case neither part ran.
+pub struct DayRun {
+    pub day: &'static Day,
+    pub result: Result<[PartResult; 2], SolveError>,
+    pub parse_time: Duration,
+    // Like the results, `None` for parts that did not run.
+    pub part_times: [Option<Duration>; 2],
+}
+
+impl DayRun {
+    // A part that is not implemented yet is not a failure.
+    pub fn failed(&self) -> bool {
+        match &self.result {
+            Ok(parts) => parts.iter().any(
+                |p: &PartResult| matches!(p, Some(Err(e)) if *e != SolveError::NotImplemented),
+            ),
+            Err(_) => true,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.part_times.iter().flatten().sum::<Duration>()
+    }
+}
+
+// Runs one step of a solver (parsing or a single part), turning a panic inside the
+// solver into an error for that step so the remaining steps still get a chance to run.
+pub fn run_step<T>(f: impl FnOnce() -> Result<T, SolveError>) -> Result<T, SolveError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload: Box<dyn Any + Send>| {
+        let message: String = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+
+        Err(SolveError::Panicked(message))
+    })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start: Instant = Instant::now();
+    let result: T = f();
+    (result, start.elapsed())
+}
+
+// Parses the input and runs both parts, or only `part` if one is given, timing each
+// step on its own.
+pub fn run_day(day: &'static Day, input: &str, part: Option<u8>) -> DayRun {
+    let (prepared, parse_time): (Result<Box<dyn Prepared>, SolveError>, Duration) =
+        timed(|| run_step(|| day.solver.prepare(input)));
+
+    let mut part_times: [Option<Duration>; 2] = [None, None];
+    let result: Result<[PartResult; 2], SolveError> =
+        prepared.map(|prepared: Box<dyn Prepared>| {
+            let mut parts: [PartResult; 2] = [None, None];
+            for (i, n) in [1, 2].into_iter().enumerate() {
+                if part.is_some_and(|p: u8| p != n) {
+                    continue;
+                }
+                let (answer, time): (Result<Answer, SolveError>, Duration) = timed(|| {
+                    run_step(|| {
+                        if n == 1 {
+                            prepared.part1()
+                        } else {
+                            prepared.part2()
+                        }
+                    })
+                });
+                parts[i] = Some(answer);
+                part_times[i] = Some(time);
+            }
+            parts
+        });
+
+    DayRun {
+        day,
+        result,
+        parse_time,
+        part_times,
+    }
+}
+
+// Runs every day on its input using up to `jobs` threads, each taking the next day that
+// is not taken yet. The runs come back in the order of the days.
+pub fn run_days(work: &[(&'static Day, &str)], part: Option<u8>, jobs: usize) -> Vec<DayRun> {
+    let next: AtomicUsize = AtomicUsize::new(0);
+    let worker = || -> Vec<(usize, DayRun)> {
+        let mut runs: Vec<(usize, DayRun)> = Vec::new();
+        loop {
+            let i: usize = next.fetch_add(1, Ordering::Relaxed);
+            let Some(&(day, input)) = work.get(i) else {
+                return runs;
+            };
+            runs.push((i, run_day(day, input, part)));
+        }
+    };
+
+    let mut runs: Vec<(usize, DayRun)> = thread::scope(|scope| {
+        let workers: Vec<ScopedJoinHandle<Vec<(usize, DayRun)>>> = (0..jobs
+            .clamp(1, work.len().max(1)))
+            .map(|_| scope.spawn(worker))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w: ScopedJoinHandle<Vec<(usize, DayRun)>>| {
+                w.join().expect("solver panics are caught by run_step")
+            })
+            .collect()
+    });
+    runs.sort_by_key(|(i, _): &(usize, DayRun)| *i);
+    runs.into_iter()
+        .map(|(_, run): (usize, DayRun)| run)
+        .collect()
+}
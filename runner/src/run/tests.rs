@@ -0,0 +1,106 @@
+use super::{DayRun, run_day, run_days, run_step};
+use aoc_core::{Answer, Day, SolveError, Solver};
+
+struct Sample;
+
+impl Solver for Sample {
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input
+            .split_whitespace()
+            .map(|s: &str| {
+                s.parse()
+                    .map_err(|_| SolveError::InvalidInput(s.to_string()))
+            })
+            .collect()
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::from(parsed.iter().sum::<i64>()))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        if parsed.is_empty() {
+            panic!("nothing to multiply");
+        }
+        Err(SolveError::NotImplemented)
+    }
+}
+
+static SAMPLE: Day = Day {
+    year: 2000,
+    day: 1,
+    name: "Sample",
+    solver: &Sample,
+};
+
+#[test]
+fn run_step_turns_panics_into_errors() {
+    let result: Result<u8, SolveError> = run_step(|| panic!("boom {}", 1));
+    assert_eq!(result, Err(SolveError::Panicked("boom 1".to_string())));
+}
+
+#[test]
+fn run_day_runs_both_parts() {
+    let run: DayRun = run_day(&SAMPLE, "1 2 3", None);
+    assert!(run.part_times.iter().all(Option::is_some));
+    assert_eq!(
+        run.elapsed(),
+        run.parse_time + run.part_times[0].unwrap() + run.part_times[1].unwrap()
+    );
+    let [part1, part2] = run.result.unwrap();
+    assert_eq!(part1.unwrap().unwrap(), "6");
+    assert_eq!(part2, Some(Err(SolveError::NotImplemented)));
+}
+
+#[test]
+fn run_day_can_run_a_single_part() {
+    // Part 2 would panic on empty input, but only part 1 is run
+    let run: DayRun = run_day(&SAMPLE, "", Some(1));
+    assert!(run.part_times[0].is_some());
+    assert_eq!(run.part_times[1], None);
+    let [part1, part2] = run.result.unwrap();
+    assert_eq!(part1.unwrap().unwrap(), "0");
+    assert_eq!(part2, None);
+}
+
+#[test]
+fn run_day_reports_failures() {
+    // An unimplemented part alone is not a failure
+    assert!(!run_day(&SAMPLE, "1 2 3", None).failed());
+
+    let run: DayRun = run_day(&SAMPLE, "", None);
+    assert!(run.failed());
+    assert!(matches!(
+        run.result.unwrap()[1],
+        Some(Err(SolveError::Panicked(_)))
+    ));
+
+    let run: DayRun = run_day(&SAMPLE, "1 x", None);
+    assert!(run.failed());
+    assert_eq!(
+        run.result.err(),
+        Some(SolveError::InvalidInput("x".to_string()))
+    );
+}
+
+#[test]
+fn run_days_keeps_the_order_of_the_days() {
+    let inputs: Vec<String> = (1..=20).map(|n: i64| format!("{} {}", n, n)).collect();
+    let work: Vec<(&'static Day, &str)> = inputs
+        .iter()
+        .map(|input: &String| (&SAMPLE, input.as_str()))
+        .collect();
+
+    for jobs in [1, 3, 64] {
+        let runs: Vec<DayRun> = run_days(&work, Some(1), jobs);
+        let answers: Vec<String> = runs
+            .into_iter()
+            .map(|run: DayRun| run.result.unwrap()[0].clone().unwrap().unwrap().to_string())
+            .collect();
+        let expected: Vec<String> = (1..=20).map(|n: i64| (2 * n).to_string()).collect();
+        assert_eq!(answers, expected, "with {jobs} jobs");
+    }
+    assert!(run_days(&[], None, 4).is_empty());
+}
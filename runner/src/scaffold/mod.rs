@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests;
+
+use aoc_core::InputLocator;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The files of a new day and of a new year crate. Placeholders: `{{year}}`, `{{day}}`
+// (e.g. 7) and `{{dd}}` (e.g. 07).
+const DAY_TEMPLATES: &[(&str, &str)] = &[
+    ("mod.rs", include_str!("../../templates/mod.rs.tmpl")),
+    ("tests.rs", include_str!("../../templates/tests.rs.tmpl")),
+    (
+        "README.txt",
+        include_str!("../../templates/README.txt.tmpl"),
+    ),
+    (
+        "examples.toml",
+        include_str!("../../templates/examples.toml.tmpl"),
+    ),
+    ("input.in", ""),
+];
+const CARGO_TEMPLATE: &str = include_str!("../../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../../templates/lib.rs.tmpl");
+
+// The name a day is registered under until the real one is known.
+pub const UNNAMED: &str = "???";
+
+pub fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{dd}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+// Adds `dayNN::DayNN => "name",` to the `register_days!` invocation in a year crate's
+// lib.rs, keeping the days in order.
+pub fn register_day(lib: &str, day: u8, name: &str) -> Result<String, String> {
+    let module: String = format!("day{:02}", day);
+    let lines: Vec<&str> = lib.lines().collect();
+
+    let registered = |line: &str| -> Option<u8> {
+        let (module, _) = line.trim().split_once("::")?;
+        module.strip_prefix("day")?.parse().ok()
+    };
+    if lines.iter().any(|l: &&str| registered(l) == Some(day)) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    // After the last earlier day, or right after the `year:` line if there is none.
+    let after: usize = lines
+        .iter()
+        .rposition(|l: &&str| registered(l).is_some_and(|d: u8| d < day))
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|l: &&str| l.trim_start().starts_with("year:"))
+        })
+        .ok_or_else(|| "no `register_days!` invocation found".to_string())?;
+
+    let mut out: Vec<String> = lines.iter().map(|l: &&str| l.to_string()).collect();
+    out.insert(
+        after + 1,
+        format!("    {}::Day{:02} => {:?},", module, day, name),
+    );
+    Ok(out.join("\n") + "\n")
+}
+
+// Replaces the placeholder name of a day registered by `new` with its real name. Days
+// that already have a name are left alone.
+pub fn rename_day(lib: &str, day: u8, name: &str) -> String {
+    let placeholder: String = format!("day{:02}::Day{:02} => {:?},", day, day, UNNAMED);
+    lib.replace(
+        &placeholder,
+        &format!("day{:02}::Day{:02} => {:?},", day, day, name),
+    )
+}
+
+// Adds a member to the `members = [...]` list of the workspace Cargo.toml.
+pub fn add_workspace_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start: usize = manifest
+        .find("members")
+        .and_then(|i: usize| manifest[i..].find('[').map(|j: usize| i + j + 1))
+        .ok_or_else(|| "no workspace `members` list found".to_string())?;
+    let end: usize = manifest[start..]
+        .find(']')
+        .map(|i: usize| start + i)
+        .ok_or_else(|| "unterminated workspace `members` list".to_string())?;
+
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|m: &&str| !m.is_empty())
+        .collect();
+    let quoted: String = format!("{:?}", member);
+    if members.contains(&quoted.as_str()) {
+        return Ok(manifest.to_string());
+    }
+    members.push(&quoted);
+
+    let list: String = members
+        .iter()
+        .map(|m: &&str| format!("\n    {},", m))
+        .collect();
+    Ok(format!(
+        "{}{}\n{}",
+        &manifest[..start],
+        list,
+        &manifest[end..]
+    ))
+}
+
+// Adds a path dependency on a year crate next to the runner's other year crates.
+pub fn add_dependency(manifest: &str, krate: &str) -> Result<String, String> {
+    let line: String = format!(
+        "{} = {{ version = \"0.1.0\", path = \"../{}\" }}",
+        krate, krate
+    );
+    let lines: Vec<&str> = manifest.lines().collect();
+    if lines
+        .iter()
+        .any(|l: &&str| l.split('=').next().is_some_and(|k: &str| k.trim() == krate))
+    {
+        return Ok(manifest.to_string());
+    }
+
+    let after: usize = lines
+        .iter()
+        .rposition(|l: &&str| l.starts_with("year"))
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|l: &&str| l.trim() == "[dependencies]")
+        })
+        .ok_or_else(|| "no `[dependencies]` section found".to_string())?;
+
+    let mut out: Vec<String> = lines.iter().map(|l: &&str| l.to_string()).collect();
+    out.insert(after + 1, line);
+    Ok(out.join("\n") + "\n")
+}
+
+// Adds `yearNNNN::DAYS` to the `YEARS` table of the runner.
+pub fn register_year(years: &str, krate: &str) -> Result<String, String> {
+    let start: usize = years
+        .find("YEARS")
+        .and_then(|i: usize| years[i..].find("&[").map(|j: usize| i + j + 2))
+        .ok_or_else(|| "no `YEARS` table found".to_string())?;
+    let end: usize = years[start..]
+        .find("];")
+        .map(|i: usize| start + i)
+        .ok_or_else(|| "unterminated `YEARS` table".to_string())?;
+
+    let mut tables: Vec<&str> = years[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|t: &&str| !t.is_empty())
+        .collect();
+    let table: String = format!("{}::DAYS", krate);
+    if tables.contains(&table.as_str()) {
+        return Ok(years.to_string());
+    }
+    tables.push(&table);
+
+    Ok(format!(
+        "{}{}{}",
+        &years[..start],
+        tables.join(", "),
+        &years[end..]
+    ))
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let text: String = fs::read_to_string(path)
+        .map_err(|e: io::Error| format!("cannot read {}: {}", path.display(), e))?;
+    let text: String = change(&text).map_err(|e: String| format!("{}: {}", path.display(), e))?;
+    fs::write(path, text).map_err(|e: io::Error| format!("cannot write {}: {}", path.display(), e))
+}
+
+fn create(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e: io::Error| format!("cannot create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content)
+        .map_err(|e: io::Error| format!("cannot write {}: {}", path.display(), e))
+}
+
+// Creates a new day in `workspace` and registers it, creating the year crate first if
+// it does not exist yet. Returns the files that were created or changed.
+pub fn new_day(workspace: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let krate: String = format!("year{}", year);
+    let crate_dir: PathBuf = workspace.join(&krate);
+    let lib: PathBuf = crate_dir.join("src").join("lib.rs");
+    let day_dir: PathBuf = InputLocator::new(workspace).day_dir(year, day);
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let mut touched: Vec<PathBuf> = Vec::new();
+    if !crate_dir.exists() {
+        let manifest: PathBuf = crate_dir.join("Cargo.toml");
+        create(&manifest, &render(CARGO_TEMPLATE, year, day))?;
+        create(&lib, &render(LIB_TEMPLATE, year, day))?;
+        touched.extend([manifest, lib.clone()]);
+
+        let paths: [PathBuf; 3] = [
+            workspace.join("Cargo.toml"),
+            workspace.join("runner").join("Cargo.toml"),
+            workspace.join("runner").join("src").join("years.rs"),
+        ];
+        edit(&paths[0], |t: &str| add_workspace_member(t, &krate))?;
+        edit(&paths[1], |t: &str| add_dependency(t, &krate))?;
+        edit(&paths[2], |t: &str| register_year(t, &krate))?;
+        touched.extend(paths);
+    }
+
+    // Registering first means nothing is left half-created if the day is taken.
+    edit(&lib, |t: &str| register_day(t, day, UNNAMED))?;
+    if !touched.contains(&lib) {
+        touched.push(lib);
+    }
+
+    for (file, template) in DAY_TEMPLATES {
+        let path: PathBuf = day_dir.join(file);
+        create(&path, &render(template, year, day))?;
+        touched.push(path);
+    }
+
+    Ok(touched)
+}
@@ -0,0 +1,157 @@
+use super::{
+    add_dependency, add_workspace_member, new_day, register_day, register_year, rename_day, render,
+};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const WORKSPACE: &str = "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"runner\",\n    \"year2025\",\n    \"aoc-core\",\n]\n";
+const RUNNER: &str = "[package]\nname = \"runner\"\n\n[dependencies]\naoc-core = { version = \"0.1.0\", path = \"../aoc-core\" }\nyear2025 = { version = \"0.1.0\", path = \"../year2025\" }\n";
+const YEARS: &str = "use aoc_core::Day;\n\npub static YEARS: &[&[Day]] = &[year2025::DAYS];\n";
+const LIB: &str = "aoc_core::register_days! {\n    year: 2025,\n    day01::Day01 => \"Secret Entrance\",\n    day03::Day03 => \"Lobby\",\n}\n";
+
+fn workspace(name: &str) -> PathBuf {
+    let root: PathBuf = env::temp_dir().join(format!(
+        "aoc_runner_scaffold_{}_{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    for (path, content) in [
+        ("Cargo.toml", WORKSPACE),
+        ("runner/Cargo.toml", RUNNER),
+        ("runner/src/years.rs", YEARS),
+        ("year2025/src/lib.rs", LIB),
+    ] {
+        let path: PathBuf = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    root
+}
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn renders_placeholders() {
+    assert_eq!(
+        render("year{{year}}/day{{dd}}: Day{{dd}} is day {{day}}", 2025, 7),
+        "year2025/day07: Day07 is day 7"
+    );
+}
+
+#[test]
+fn registers_days_in_order() {
+    let lib: String = register_day(LIB, 2, "Gift Shop").unwrap();
+    assert!(lib.contains(
+        "day01::Day01 => \"Secret Entrance\",\n    day02::Day02 => \"Gift Shop\",\n    day03::Day03"
+    ));
+
+    let lib: String = register_day(LIB, 13, "???").unwrap();
+    assert!(lib.contains("day03::Day03 => \"Lobby\",\n    day13::Day13 => \"???\",\n}"));
+
+    let empty: &str = "aoc_core::register_days! {\n    year: 2026,\n}\n";
+    assert_eq!(
+        register_day(empty, 1, "First").unwrap(),
+        "aoc_core::register_days! {\n    year: 2026,\n    day01::Day01 => \"First\",\n}\n"
+    );
+
+    assert!(register_day(LIB, 3, "Again").is_err());
+    assert!(register_day("fn main() {}\n", 1, "Nowhere").is_err());
+}
+
+#[test]
+fn renames_only_placeholder_days() {
+    let lib: String = register_day(LIB, 13, "???").unwrap();
+    let renamed: String = rename_day(&lib, 13, "Some \"Quoted\" Name");
+    assert!(renamed.contains("day13::Day13 => \"Some \\\"Quoted\\\" Name\","));
+    assert_eq!(rename_day(&renamed, 13, "Other"), renamed);
+    assert_eq!(rename_day(LIB, 3, "Other"), LIB);
+}
+
+#[test]
+fn adds_workspace_members_once() {
+    let manifest: String = add_workspace_member(WORKSPACE, "year2026").unwrap();
+    assert!(manifest.ends_with(
+        "members = [\n    \"runner\",\n    \"year2025\",\n    \"aoc-core\",\n    \"year2026\",\n]\n"
+    ));
+    assert_eq!(
+        add_workspace_member(&manifest, "year2026").unwrap(),
+        manifest
+    );
+
+    let inline: String = add_workspace_member("[workspace]\nmembers = [\"a\"]\n", "b").unwrap();
+    assert_eq!(
+        inline,
+        "[workspace]\nmembers = [\n    \"a\",\n    \"b\",\n]\n"
+    );
+}
+
+#[test]
+fn adds_year_dependencies_and_tables() {
+    let manifest: String = add_dependency(RUNNER, "year2026").unwrap();
+    assert!(manifest.ends_with(
+        "year2025 = { version = \"0.1.0\", path = \"../year2025\" }\nyear2026 = { version = \"0.1.0\", path = \"../year2026\" }\n"
+    ));
+    assert_eq!(add_dependency(&manifest, "year2026").unwrap(), manifest);
+
+    let years: String = register_year(YEARS, "year2026").unwrap();
+    assert!(years.ends_with("&[year2025::DAYS, year2026::DAYS];\n"));
+    assert_eq!(register_year(&years, "year2026").unwrap(), years);
+}
+
+#[test]
+fn creates_a_day_in_an_existing_year() {
+    let root: PathBuf = workspace("existing_year");
+
+    let touched: Vec<PathBuf> = new_day(&root, 2025, 2).unwrap();
+    assert_eq!(touched.len(), 6);
+    assert!(read(&root, "year2025/src/lib.rs").contains("day02::Day02 => \"???\","));
+    assert!(read(&root, "year2025/src/day02/mod.rs").contains("impl Solver for Day02 {"));
+    assert!(read(&root, "year2025/src/day02/tests.rs").contains("check_examples(&Day02, 2025, 2)"));
+    assert!(read(&root, "year2025/src/day02/examples.toml").contains("input = \"example1.in\""));
+    assert!(read(&root, "year2025/src/day02/README.txt").starts_with("--- Day 2: ??? ---"));
+    assert_eq!(read(&root, "year2025/src/day02/input.in"), "");
+    // The workspace is left alone.
+    assert_eq!(read(&root, "Cargo.toml"), WORKSPACE);
+
+    assert!(
+        new_day(&root, 2025, 2)
+            .unwrap_err()
+            .contains("already exists")
+    );
+    // A registered day without files is refused before anything is written.
+    assert!(
+        new_day(&root, 2025, 3)
+            .unwrap_err()
+            .contains("already registered")
+    );
+    assert!(!root.join("year2025/src/day03").exists());
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn creates_a_new_year_crate() {
+    let root: PathBuf = workspace("new_year");
+
+    new_day(&root, 2026, 1).unwrap();
+    assert!(read(&root, "year2026/Cargo.toml").contains("name = \"year2026\""));
+    assert_eq!(
+        read(&root, "year2026/src/lib.rs"),
+        "aoc_core::register_days! {\n    year: 2026,\n    day01::Day01 => \"???\",\n}\n"
+    );
+    assert!(read(&root, "Cargo.toml").contains("    \"year2026\",\n]"));
+    assert!(read(&root, "runner/Cargo.toml").contains("path = \"../year2026\""));
+    assert!(read(&root, "runner/src/years.rs").contains("year2026::DAYS"));
+    assert!(root.join("year2026/src/day01/mod.rs").is_file());
+
+    // The second day of the year only touches the year crate.
+    let touched: Vec<PathBuf> = new_day(&root, 2026, 2).unwrap();
+    assert_eq!(touched[0], root.join("year2026/src/lib.rs"));
+    assert_eq!(touched.len(), 6);
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests;
+
+use aoc_core::{Day, Registry};
+use std::fmt;
+use std::str::FromStr;
+
+// What to run, as given on the command line:
+//   all             every registered day
+//   2025            every day of a year
+//   2025-03         a single day
+//   2025-03..07     an inclusive range of days
+//   2025-01,04,09   a list of days (items may themselves be ranges)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Year(u16),
+    Days { year: u16, days: Vec<u8> },
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day `{s}`, expected a number between 1 and 25"
+        )),
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+        if s == "all" {
+            return Ok(Target::All);
+        }
+
+        let (year, days): (&str, Option<&str>) = match s.split_once('-') {
+            Some((year, days)) => (year, Some(days)),
+            None => (s, None),
+        };
+        let year: u16 = year
+            .parse()
+            .map_err(|_| format!("invalid year `{year}` in target `{s}`"))?;
+
+        let Some(days) = days else {
+            return Ok(Target::Year(year));
+        };
+
+        let mut selected: Vec<u8> = Vec::new();
+        for item in days.split(',') {
+            match item.split_once("..") {
+                Some((from, to)) => {
+                    let (from, to): (u8, u8) = (parse_day(from)?, parse_day(to)?);
+                    if from > to {
+                        return Err(format!("empty day range `{item}` in target `{s}`"));
+                    }
+                    selected.extend(from..=to);
+                }
+                None => selected.push(parse_day(item)?),
+            }
+        }
+        selected.sort_unstable();
+        selected.dedup();
+
+        Ok(Target::Days {
+            year,
+            days: selected,
+        })
+    }
+}
+
+// Written back as a list of days, which parses to the same target.
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::All => f.write_str("all"),
+            Target::Year(year) => write!(f, "{}", year),
+            Target::Days { year, days } => {
+                let days: Vec<String> = days.iter().map(|d: &u8| format!("{:02}", d)).collect();
+                write!(f, "{}-{}", year, days.join(","))
+            }
+        }
+    }
+}
+
+impl Target {
+    pub fn is_single_day(&self) -> bool {
+        matches!(self, Target::Days { days, .. } if days.len() == 1)
+    }
+
+    // The registered days this target refers to, in order. Naming a year or a day
+    // that has no solver is an error, including any day inside a range.
+    pub fn select(&self, registry: &Registry) -> Result<Vec<&'static Day>, String> {
+        let year: u16 = match self {
+            Target::All => return Ok(registry.days().collect()),
+            Target::Year(year) | Target::Days { year, .. } => *year,
+        };
+        if !registry.years().contains(&year) {
+            return Err(format!("Year {} not implemented", year));
+        }
+
+        match self {
+            Target::Days { days, .. } => days
+                .iter()
+                .map(|&day: &u8| {
+                    registry
+                        .get(year, day)
+                        .ok_or_else(|| format!("Day {} not implemented for year {}", day, year))
+                })
+                .collect(),
+            _ => Ok(registry.days().filter(|d: &&Day| d.year == year).collect()),
+        }
+    }
+
+    // The (year, day) pairs this target names. Unlike `select`, explicitly named days
+    // need no solver yet, for commands such as `fetch` that work on puzzles rather
+    // than on solutions.
+    pub fn puzzles(&self, registry: &Registry) -> Result<Vec<(u16, u8)>, String> {
+        match self {
+            Target::Days { year, days } => Ok(days.iter().map(|&day: &u8| (*year, day)).collect()),
+            _ => Ok(self
+                .select(registry)?
+                .into_iter()
+                .map(|d: &Day| (d.year, d.day))
+                .collect()),
+        }
+    }
+}
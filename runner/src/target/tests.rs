@@ -0,0 +1,151 @@
+use super::Target;
+use aoc_core::{Answer, Day, Registry, SolveError, Solver};
+
+struct Unused;
+
+impl Solver for Unused {
+    type Parsed = ();
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+}
+
+const fn day(year: u16, day: u8) -> Day {
+    Day {
+        year,
+        day,
+        name: "",
+        solver: &Unused,
+    }
+}
+
+static YEAR_2024: &[Day] = &[day(2024, 1), day(2024, 2)];
+static YEAR_2025: &[Day] = &[day(2025, 1), day(2025, 2), day(2025, 3), day(2025, 4)];
+static REGISTRY: Registry = Registry::new(&[YEAR_2025, YEAR_2024]);
+
+fn selected(target: &str) -> Result<Vec<(u16, u8)>, String> {
+    let target: Target = target.parse()?;
+    Ok(target
+        .select(&REGISTRY)?
+        .into_iter()
+        .map(|d: &Day| (d.year, d.day))
+        .collect())
+}
+
+#[test]
+fn parses_target_expressions() {
+    assert_eq!("all".parse(), Ok(Target::All));
+    assert_eq!("2025".parse(), Ok(Target::Year(2025)));
+    assert_eq!(
+        "2025-03".parse(),
+        Ok(Target::Days {
+            year: 2025,
+            days: vec![3]
+        })
+    );
+    assert_eq!(
+        "2025-03..07".parse(),
+        Ok(Target::Days {
+            year: 2025,
+            days: vec![3, 4, 5, 6, 7]
+        })
+    );
+    assert_eq!(
+        "2025-09,01,04".parse(),
+        Ok(Target::Days {
+            year: 2025,
+            days: vec![1, 4, 9]
+        })
+    );
+    assert_eq!(
+        "2025-01,03..04,04".parse(),
+        Ok(Target::Days {
+            year: 2025,
+            days: vec![1, 3, 4]
+        })
+    );
+}
+
+#[test]
+fn targets_are_written_back_as_they_parse() {
+    for (text, written) in [
+        ("all", "all"),
+        ("2025", "2025"),
+        ("2025-3", "2025-03"),
+        ("2025-01..03,12", "2025-01,02,03,12"),
+    ] {
+        let target: Target = text.parse().unwrap();
+        assert_eq!(target.to_string(), written);
+        assert_eq!(written.parse::<Target>(), Ok(target));
+    }
+}
+
+#[test]
+fn rejects_malformed_targets() {
+    assert!("twenty".parse::<Target>().is_err());
+    assert!("2025-".parse::<Target>().is_err());
+    assert!("2025-00".parse::<Target>().is_err());
+    assert!("2025-26".parse::<Target>().is_err());
+    assert!("2025-07..03".parse::<Target>().is_err());
+    assert!("2025-01,,02".parse::<Target>().is_err());
+}
+
+#[test]
+fn only_a_single_day_is_a_single_day() {
+    assert!("2025-03".parse::<Target>().unwrap().is_single_day());
+    assert!("2025-03..03".parse::<Target>().unwrap().is_single_day());
+    assert!(!"2025-03..04".parse::<Target>().unwrap().is_single_day());
+    assert!(!"2025".parse::<Target>().unwrap().is_single_day());
+}
+
+#[test]
+fn selects_registered_days_in_order() {
+    assert_eq!(
+        selected("all"),
+        Ok(vec![
+            (2024, 1),
+            (2024, 2),
+            (2025, 1),
+            (2025, 2),
+            (2025, 3),
+            (2025, 4)
+        ])
+    );
+    assert_eq!(selected("2024"), Ok(vec![(2024, 1), (2024, 2)]));
+    assert_eq!(selected("2025-02..03"), Ok(vec![(2025, 2), (2025, 3)]));
+    assert_eq!(selected("2025-04,01"), Ok(vec![(2025, 1), (2025, 4)]));
+}
+
+#[test]
+fn selecting_unknown_days_is_an_error() {
+    assert_eq!(
+        selected("2023"),
+        Err("Year 2023 not implemented".to_string())
+    );
+    assert_eq!(
+        selected("2025-03..05"),
+        Err("Day 5 not implemented for year 2025".to_string())
+    );
+}
+
+#[test]
+fn puzzles_may_name_days_without_solvers() {
+    let puzzles = |target: &str| target.parse::<Target>().unwrap().puzzles(&REGISTRY);
+
+    assert_eq!(
+        puzzles("2025-04..06"),
+        Ok(vec![(2025, 4), (2025, 5), (2025, 6)])
+    );
+    assert_eq!(puzzles("2023-01"), Ok(vec![(2023, 1)]));
+    assert_eq!(puzzles("2024"), Ok(vec![(2024, 1), (2024, 2)]));
+    assert!(puzzles("2023").is_err());
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests;
+
+use crate::json::{self, Object, Value};
+use crate::report::{self, format_duration};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+// Watched files are checked for changes this often.
+pub const POLL: Duration = Duration::from_millis(500);
+
+// The modification time of every watched file, `None` for files that do not exist (yet).
+pub type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+fn add_files(path: &Path, snapshot: &mut Snapshot) {
+    match fs::read_dir(path) {
+        Ok(entries) => {
+            for entry in entries.flatten() {
+                add_files(&entry.path(), snapshot);
+            }
+        }
+        Err(_) => {
+            let modified: Option<SystemTime> = fs::metadata(path)
+                .and_then(|m: fs::Metadata| m.modified())
+                .ok();
+            snapshot.insert(path.to_path_buf(), modified);
+        }
+    }
+}
+
+// Directories stand for every file below them, so files added to them are noticed too.
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot: Snapshot = Snapshot::new();
+    for path in paths {
+        add_files(path, &mut snapshot);
+    }
+    snapshot
+}
+
+// The files that were created, modified or removed between two snapshots.
+pub fn changes(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = new
+        .iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        old.iter()
+            .filter(|(path, modified)| modified.is_some() && !new.contains_key(*path))
+            .map(|(path, _)| path.clone()),
+    );
+    changed.sort();
+    changed
+}
+
+// One part as reported by `runner run --format json`. A day whose input did not parse
+// has a single outcome without a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Result<String, String>,
+    pub time: Duration,
+}
+
+fn outcome(fields: &Object) -> Result<Outcome, String> {
+    let answer: Result<String, String> = match fields.get("ok") {
+        Some(Value::Bool(true)) => Ok(json::string(fields, "answer")?.to_string()),
+        _ => Err(json::string(fields, "error")?.to_string()),
+    };
+    let part: Option<u8> = match fields.get("part") {
+        Some(Value::Number(part)) => {
+            Some(u8::try_from(*part).map_err(|_| "`part` is out of range")?)
+        }
+        _ => None,
+    };
+
+    Ok(Outcome {
+        year: u16::try_from(json::number(fields, "year")?).map_err(|_| "`year` is out of range")?,
+        day: u8::try_from(json::number(fields, "day")?).map_err(|_| "`day` is out of range")?,
+        part,
+        answer,
+        time: Duration::from_nanos(json::number(fields, "time_ns")?),
+    })
+}
+
+pub fn parse_outcomes(text: &str) -> Result<Vec<Outcome>, String> {
+    json::parse_array(text)?.iter().map(outcome).collect()
+}
+
+fn answer_cell(answer: &str) -> String {
+    if answer.contains('\n') {
+        "(multi-line)".to_string()
+    } else {
+        answer.to_string()
+    }
+}
+
+// The answers of the last run next to those of the run before and the confirmed ones,
+// which `known` looks up by year, day and part.
+pub fn table(
+    outcomes: &[Outcome],
+    previous: &[Outcome],
+    known: impl Fn(u16, u8, u8) -> Option<String>,
+) -> String {
+    let mut rows: Vec<Vec<String>> = vec![
+        ["Day", "Part", "Answer", "Time", "Previous", "Known"]
+            .map(str::to_string)
+            .to_vec(),
+    ];
+
+    for outcome in outcomes {
+        let (answer, time): (String, String) = match &outcome.answer {
+            Ok(answer) => (answer_cell(answer), format_duration(outcome.time)),
+            Err(e) => (e.clone(), String::new()),
+        };
+        let before: String = match previous.iter().find(|p: &&Outcome| {
+            (p.year, p.day, p.part) == (outcome.year, outcome.day, outcome.part)
+        }) {
+            None => String::new(),
+            Some(p) if p.answer == outcome.answer => "same".to_string(),
+            Some(Outcome {
+                answer: Ok(answer), ..
+            }) => answer_cell(answer),
+            Some(Outcome { answer: Err(e), .. }) => e.clone(),
+        };
+        let confirmed: String = match outcome
+            .part
+            .and_then(|part: u8| known(outcome.year, outcome.day, part))
+        {
+            None => String::new(),
+            Some(known) if outcome.answer.as_ref() == Ok(&known) => {
+                format!("{} (correct)", answer_cell(&known))
+            }
+            Some(known) => format!("{} (wrong)", answer_cell(&known)),
+        };
+
+        rows.push(vec![
+            format!("{}-{:02}", outcome.year, outcome.day),
+            outcome.part.map_or("-".to_string(), |p: u8| p.to_string()),
+            answer,
+            time,
+            before,
+            confirmed,
+        ]);
+    }
+
+    report::table(&rows, &[false, false, false, true, false, false])
+}
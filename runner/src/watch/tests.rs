@@ -0,0 +1,92 @@
+use super::{Outcome, Snapshot, changes, parse_outcomes, snapshot, table};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+fn outcome(day: u8, part: u8, answer: Result<&str, &str>) -> Outcome {
+    Outcome {
+        year: 2025,
+        day,
+        part: Some(part),
+        answer: answer.map(str::to_string).map_err(str::to_string),
+        time: Duration::from_micros(1_500),
+    }
+}
+
+#[test]
+fn snapshots_notice_created_modified_and_removed_files() {
+    let dir: PathBuf = env::temp_dir().join(format!("aoc_runner_watch_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("day01")).unwrap();
+    fs::write(dir.join("day01").join("mod.rs"), "a").unwrap();
+    let input: PathBuf = dir.join("day01.in");
+    let paths: Vec<PathBuf> = vec![dir.join("day01"), input.clone()];
+
+    let before: Snapshot = snapshot(&paths);
+    assert_eq!(before.len(), 2);
+    assert_eq!(before[&input], None);
+    assert!(changes(&before, &snapshot(&paths)).is_empty());
+
+    fs::write(&input, "1 2 3").unwrap();
+    fs::write(dir.join("day01").join("example1.in"), "1").unwrap();
+    let after: Snapshot = snapshot(&paths);
+    assert_eq!(
+        changes(&before, &after),
+        vec![dir.join("day01").join("example1.in"), input.clone()]
+    );
+
+    fs::remove_file(dir.join("day01").join("example1.in")).unwrap();
+    assert_eq!(
+        changes(&after, &snapshot(&paths)),
+        vec![dir.join("day01").join("example1.in")]
+    );
+
+    // Modification times only tell changes apart that are far enough apart.
+    let mut touched: Snapshot = after.clone();
+    touched.insert(input.clone(), Some(SystemTime::UNIX_EPOCH));
+    assert_eq!(changes(&after, &touched), vec![input]);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn parses_the_json_output_of_runs() {
+    let text: &str = "[\n  {\"year\":2025,\"day\":7,\"name\":\"Laboratories\",\"part\":1,\"input\":null,\"ok\":true,\"answer\":\"21\",\"time_ns\":1500000},\n  {\"year\":2025,\"day\":7,\"name\":\"Laboratories\",\"part\":2,\"input\":null,\"ok\":false,\"error\":\"not implemented\",\"time_ns\":1500000},\n  {\"year\":2025,\"day\":8,\"name\":\"Playground\",\"input\":\"in\",\"ok\":false,\"error\":\"invalid input: x\",\"time_ns\":0}\n]\n";
+
+    let outcomes: Vec<Outcome> = parse_outcomes(text).unwrap();
+    assert_eq!(outcomes[0], outcome(7, 1, Ok("21")));
+    assert_eq!(outcomes[1], outcome(7, 2, Err("not implemented")));
+    assert_eq!(outcomes[2].part, None);
+    assert_eq!(outcomes[2].answer, Err("invalid input: x".to_string()));
+
+    assert!(parse_outcomes("").is_err());
+    assert!(parse_outcomes("[{\"year\":2025}]").is_err());
+}
+
+#[test]
+fn table_compares_with_previous_and_known_answers() {
+    let outcomes: Vec<Outcome> = vec![
+        outcome(10, 1, Ok("7")),
+        outcome(10, 2, Ok("33")),
+        outcome(11, 1, Err("not implemented")),
+    ];
+    let previous: Vec<Outcome> = vec![outcome(10, 1, Ok("7")), outcome(10, 2, Ok("#\n#"))];
+    let known = |_year: u16, day: u8, part: u8| -> Option<String> {
+        match (day, part) {
+            (10, 1) => Some("7".to_string()),
+            (10, 2) => Some("35".to_string()),
+            _ => None,
+        }
+    };
+
+    assert_eq!(
+        table(&outcomes, &previous, known),
+        "\
+Day      Part  Answer             Time  Previous      Known
+2025-10  1     7                1.5 ms  same          7 (correct)
+2025-10  2     33               1.5 ms  (multi-line)  35 (wrong)
+2025-11  1     not implemented
+"
+    );
+}
@@ -1,68 +1,80 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::error::{ParseError, parse_token};
+use aoc_core::{Answer, SolveError, Solver};
 
 pub struct Day01;
 
 impl Solver for Day01 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn parse_instructions(input: &str) -> impl Iterator<Item = (char, i32)> + '_ {
-            input.lines().filter_map(|line: &str| {
-                let line: &str = line.trim();
+    type Parsed = Vec<(char, i32)>;
 
-                if line.is_empty() {
-                    return None;
-                }
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let mut instructions: Vec<(char, i32)> = Vec::new();
 
-                let direction: char = line.chars().next()?.to_ascii_uppercase();
-                let amount: i32 = line[1..].parse().ok()?;
+        for (i, raw) in input.lines().enumerate() {
+            let line: &str = raw.trim();
 
-                Some((direction, amount))
-            })
-        }
-        fn part1(input: &str) -> i32 {
-            let start_position: i32 = 50;
-            let mut current_position: i32 = start_position;
-            let mut stop_at_zero: i32 = 0;
-
-            for (direction, amount) in parse_instructions(input) {
-                match direction {
-                    // Use rem_euclid to handle negative numbers correctly for circular wrapping
-                    'L' => current_position = (current_position - amount).rem_euclid(100),
-                    'R' => current_position = (current_position + amount).rem_euclid(100),
-                    _ => panic!("Invalid direction: {}", direction),
-                }
+            if line.is_empty() {
+                continue;
+            }
 
-                if current_position == 0 {
-                    stop_at_zero += 1;
+            let split: usize = line.chars().next().map_or(0, char::len_utf8);
+            let (direction_token, amount_token): (&str, &str) = line.split_at(split);
+
+            let direction: char = match direction_token {
+                "L" | "l" => 'L',
+                "R" | "r" => 'R',
+                _ => {
+                    return Err(
+                        ParseError::new(i, raw, direction_token, "a direction `L` or `R`").into(),
+                    );
                 }
-            }
+            };
+            let amount: i32 = parse_token(i, raw, amount_token, "a number of clicks")?;
 
-            stop_at_zero
+            instructions.push((direction, amount));
         }
 
-        fn part2(input: &str) -> i32 {
-            let mut current_position: i32 = 50;
-            let mut total_zeros: i32 = 0;
-
-            for (direction, amount) in parse_instructions(input) {
-                for _ in 0..amount {
-                    match direction {
-                        'L' => current_position = (current_position - 1).rem_euclid(100),
-                        'R' => current_position = (current_position + 1).rem_euclid(100),
-                        _ => panic!("Unknown direction: {}", direction),
-                    }
-
-                    if current_position == 0 {
-                        total_zeros += 1;
-                    }
-                }
+        Ok(instructions)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let start_position: i32 = 50;
+        let mut current_position: i32 = start_position;
+        let mut stop_at_zero: i32 = 0;
+
+        for &(direction, amount) in parsed {
+            // Use rem_euclid to handle negative numbers correctly for circular wrapping
+            current_position = match direction {
+                'L' => (current_position - amount).rem_euclid(100),
+                _ => (current_position + amount).rem_euclid(100),
+            };
+
+            if current_position == 0 {
+                stop_at_zero += 1;
             }
+        }
+
+        Ok(Answer::from(stop_at_zero))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut current_position: i32 = 50;
+        let mut total_zeros: i32 = 0;
 
-            total_zeros
+        for &(direction, amount) in parsed {
+            let step: i32 = if direction == 'L' { -1 } else { 1 };
+
+            for _ in 0..amount {
+                current_position = (current_position + step).rem_euclid(100);
+
+                if current_position == 0 {
+                    total_zeros += 1;
+                }
+            }
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        Ok(Answer::from(total_zeros))
     }
 }
@@ -1,235 +1,201 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::error::ParseError;
+use aoc_core::math::{digit_count, factorize, lcm, range_sum, repeat_multiplier};
+use aoc_core::{Answer, SolveError, Solver};
 
 pub struct Day02;
 
-impl Solver for Day02 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        // Parse input like "a-b,c-d,..." possibly wrapped across lines/spaces
-        fn parse_ranges(input: &str) -> impl Iterator<Item = (u128, u128)> + '_ {
-            input.split(',').filter_map(|tok: &str| {
-                let s: &str = tok.trim();
-
-                if s.is_empty() {
-                    return None;
-                }
+// --- Small building blocks shared by Part 1 and Part 2 ---
+// Sum of x * multiplier for x in [min_x, max_x] such that x * multiplier in [lo, hi]
+fn sum_ap_in_range(min_x: u128, max_x: u128, multiplier: u128, lo: u128, hi: u128) -> u128 {
+    // We need x * multiplier >= lo  =>  x >= ceil(lo / multiplier)
+    let start_limit: u128 = lo.div_ceil(multiplier);
+    let start: u128 = min_x.max(start_limit);
 
-                let (a, b): (&str, &str) = s.split_once('-')?;
-                let lo: u128 = a.trim().parse().ok()?;
-                let hi: u128 = b.trim().parse().ok()?;
+    // We need x * multiplier <= hi  =>  x <= floor(hi / multiplier)
+    let end_limit: u128 = hi / multiplier;
+    let end: u128 = max_x.min(end_limit);
 
-                Some((lo.min(hi), lo.max(hi)))
-            })
-        }
+    if start > end {
+        return 0;
+    }
 
-        // --- Small building blocks shared by Part 1 and Part 2 ---
-        // Sum of x * multiplier for x in [min_x, max_x] such that x * multiplier in [lo, hi]
-        fn sum_ap_in_range(min_x: u128, max_x: u128, multiplier: u128, lo: u128, hi: u128) -> u128 {
-            // We need x * multiplier >= lo  =>  x >= ceil(lo / multiplier)
-            let start_limit: u128 = (lo + multiplier - 1) / multiplier;
-            let start: u128 = min_x.max(start_limit);
+    // Sum of arithmetic progression: sum(x) * multiplier
+    range_sum(start, end).wrapping_mul(multiplier)
+}
 
-            // We need x * multiplier <= hi  =>  x <= floor(hi / multiplier)
-            let end_limit: u128 = hi / multiplier;
-            let end: u128 = max_x.min(end_limit);
+// Sum of all numbers with total length `total_len` that are formed by repeating
+// a base block (length = total_len / repeats) exactly `repeats` times, intersected with [lo, hi].
+fn sum_exact_repeats_for_length(lo: u128, hi: u128, total_len: u32, repeats: u32) -> u128 {
+    if repeats < 2 || total_len < repeats || !total_len.is_multiple_of(repeats) {
+        return 0;
+    }
 
-            if start > end {
-                return 0;
-            }
+    let period_len: u32 = total_len / repeats;
 
-            let count: u128 = end - start + 1;
-            // Sum of arithmetic progression: sum(x) * multiplier
-            // sum(x) from start to end = (start + end) * count / 2
-            let sum_x: u128 = if (start + end) % 2 == 0 {
-                ((start + end) / 2) * count
-            } else {
-                ((start + end) * count) / 2
-            };
+    if period_len == 0 {
+        return 0;
+    }
 
-            sum_x.wrapping_mul(multiplier)
-        }
+    // Numbers of this shape do not fit in a u128, so none are in [lo, hi].
+    let Some(multiplier) = repeat_multiplier(period_len, repeats) else {
+        return 0;
+    };
+    let min_x: u128 = 10u128.pow(period_len - 1);
+    let max_x: u128 = 10u128.pow(period_len) - 1;
 
-        // Compute the decimal-repeat multiplier for a block of length `period_len`
-        // repeated exactly `repeats` times: 1 + 10^L + 10^(2L) + ...
-        fn repeat_multiplier(period_len: u32, repeats: u32) -> u128 {
-            if period_len == 0 || repeats < 2 {
-                return 0;
-            }
+    sum_ap_in_range(min_x, max_x, multiplier, lo, hi)
+}
 
-            let step: u128 = 10u128.pow(period_len);
-            let mut mult: u128 = 0;
+// Sum of all numbers in [lo, hi] that are made by exactly `repeats` repeats of a block
+// of digits (no restriction on total length beyond divisibility by repeats).
+fn sum_exact_repeats_any_length(lo: u128, hi: u128, repeats: u32) -> u128 {
+    if repeats < 2 {
+        return 0;
+    }
 
-            for _ in 0..repeats {
-                mult = mult
-                    .checked_mul(step)
-                    .and_then(|x| x.checked_add(1))
-                    .unwrap_or(u128::MAX);
-            }
+    let min_len: u32 = digit_count(lo);
+    let max_len: u32 = digit_count(hi);
+    let mut total: u128 = 0;
 
-            mult
+    for d in min_len..=max_len {
+        if d % repeats == 0 {
+            total = total.wrapping_add(sum_exact_repeats_for_length(lo, hi, d, repeats));
         }
+    }
 
-        // Sum of all numbers with total length `total_len` that are formed by repeating
-        // a base block (length = total_len / repeats) exactly `repeats` times, intersected with [lo, hi].
-        fn sum_exact_repeats_for_length(lo: u128, hi: u128, total_len: u32, repeats: u32) -> u128 {
-            if repeats < 2 || total_len < repeats || total_len % repeats != 0 {
-                return 0;
-            }
-
-            let period_len: u32 = total_len / repeats;
-
-            if period_len == 0 {
-                return 0;
-            }
-
-            let multiplier: u128 = repeat_multiplier(period_len, repeats);
-            let min_x: u128 = 10u128.pow(period_len - 1);
-            let max_x: u128 = 10u128.pow(period_len) - 1;
+    total
+}
 
-            sum_ap_in_range(min_x, max_x, multiplier, lo, hi)
+fn sum_invalid_ids_in_range(lo: u128, hi: u128) -> u128 {
+    let mut total: u128 = 0;
+    let min_len: u32 = digit_count(lo);
+    let max_len: u32 = digit_count(hi);
+
+    // Iterate over total length D
+    for d in min_len..=max_len {
+        // Check if this length overlaps with [lo, hi]
+        // Smallest number with length d: 10^(d-1)
+        // Largest number with length d: 10^d - 1
+
+        // Optimization: Primes of d
+        let primes: Vec<u32> = factorize(u64::from(d))
+            .iter()
+            .map(|&(p, _): &(u64, u32)| p as u32)
+            .collect();
+
+        if primes.is_empty() {
+            // Length 1 has no prime factors, no period <= 1/2 (since 1/2 < 1).
+            continue;
         }
 
-        // Sum of all numbers in [lo, hi] that are made by exactly `repeats` repeats of a block
-        // of digits (no restriction on total length beyond divisibility by repeats).
-        fn sum_exact_repeats_any_length(lo: u128, hi: u128, repeats: u32) -> u128 {
-            if repeats < 2 {
-                return 0;
-            }
+        // Inclusion-Exclusion Principle
+        // Union of properties P_p: periodic with base length d/p
+        // We iterate over non-empty subsets of primes.
+        let n_primes: usize = primes.len();
+        let n_subsets: i32 = 1 << n_primes;
 
-            let min_len: u32 = lo.to_string().len() as u32;
-            let max_len: u32 = hi.to_string().len() as u32;
-            let mut total: u128 = 0;
+        for i in 1..n_subsets {
+            let mut subset_lcm: u32 = 1;
+            let mut subset_size: i32 = 0;
 
-            for d in min_len..=max_len {
-                if d % repeats == 0 {
-                    total = total.wrapping_add(sum_exact_repeats_for_length(lo, hi, d, repeats));
+            for (bit, &prime) in primes.iter().enumerate() {
+                if (i >> bit) & 1 == 1 {
+                    subset_lcm = lcm(subset_lcm, prime);
+                    subset_size += 1;
                 }
             }
 
-            total
-        }
-
-        fn part1(input: &str) -> u128 {
-            let mut total_sum: u128 = 0;
+            // This subset corresponds to numbers that can be represented as repeating
+            // a block of size D/subset_lcm exactly `subset_lcm` times.
+            let term: u128 = sum_exact_repeats_for_length(lo, hi, d, subset_lcm);
 
-            for (lo, hi) in parse_ranges(input) {
-                // Part 1: numbers that are exactly two repeats of a base block (XYXY, etc.).
-                total_sum = total_sum.wrapping_add(sum_exact_repeats_any_length(lo, hi, 2));
+            if subset_size % 2 == 1 {
+                // Add
+                total = total.wrapping_add(term);
+            } else {
+                // Subtract
+                total = total.wrapping_sub(term);
             }
-
-            total_sum
         }
+    }
 
-        // For Part 2, return the sum of invalid IDs.
-        // An ID is invalid if it is made only of some sequence of digits repeated at least twice.
-        fn part2(input: &str) -> u128 {
-            let mut total_sum: u128 = 0;
-            let ranges: Vec<(u128, u128)> = parse_ranges(input).collect();
-
-            for (lo, hi) in ranges {
-                total_sum = total_sum.wrapping_add(sum_invalid_ids_in_range(lo, hi));
-            }
+    total
+}
 
-            total_sum
-        }
+// Reports `found`, which starts `at` bytes into the input, on the line it starts on.
+// Ranges may be wrapped across lines, so tokens are located in the whole input.
+fn error_at(input: &str, at: usize, found: &str, expected: &str) -> SolveError {
+    let line_start: usize = input[..at].rfind('\n').map_or(0, |i: usize| i + 1);
+    let line_end: usize = input[at..]
+        .find('\n')
+        .map_or(input.len(), |i: usize| at + i);
+    let line: &str = &input[line_start..line_end];
+    let line_index: usize = input[..line_start].matches('\n').count();
+    // `found` may run on past the end of its line; only its first line is shown.
+    let found: &str = &line[at - line_start..][..found.len().min(line_end - at)];
+    ParseError::new(line_index, line, found, expected).into()
+}
 
-        fn gcd(mut a: u32, mut b: u32) -> u32 {
-            while b != 0 {
-                let t: u32 = b;
-                b = a % b;
-                a = t;
+impl Solver for Day02 {
+    type Parsed = Vec<(u128, u128)>;
+
+    // Parse input like "a-b,c-d,..." possibly wrapped across lines/spaces
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        // A number that starts `at` bytes into the input, surrounded by whitespace or not.
+        let number = |at: usize, tok: &str, expected: &str| -> Result<u128, SolveError> {
+            let at: usize = at + (tok.len() - tok.trim_start().len());
+            tok.trim()
+                .parse()
+                .map_err(|_| error_at(input, at, tok.trim(), expected))
+        };
+
+        let mut ranges: Vec<(u128, u128)> = Vec::new();
+        let mut offset: usize = 0;
+
+        for tok in input.split(',') {
+            let at: usize = offset;
+            offset += tok.len() + 1;
+
+            if tok.trim().is_empty() {
+                continue;
             }
 
-            a
-        }
+            let Some((a, b)) = tok.split_once('-') else {
+                let at: usize = at + (tok.len() - tok.trim_start().len());
+                return Err(error_at(input, at, tok.trim(), "a range like `11-22`"));
+            };
+            let lo: u128 = number(at, a, "a range start")?;
+            let hi: u128 = number(at + a.len() + 1, b, "a range end")?;
 
-        fn lcm(a: u32, b: u32) -> u32 {
-            if a == 0 || b == 0 {
-                0
-            } else {
-                (a / gcd(a, b)) * b
-            }
+            ranges.push((lo.min(hi), lo.max(hi)));
         }
 
-        fn prime_factors(mut n: u32) -> Vec<u32> {
-            let mut factors: Vec<u32> = Vec::new();
-            let mut d: u32 = 2;
-
-            while d * d <= n {
-                if n % d == 0 {
-                    factors.push(d);
-                    while n % d == 0 {
-                        n /= d;
-                    }
-                }
-
-                d += 1;
-            }
+        Ok(ranges)
+    }
 
-            if n > 1 {
-                factors.push(n);
-            }
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut total_sum: u128 = 0;
 
-            factors
+        for &(lo, hi) in parsed {
+            // Part 1: numbers that are exactly two repeats of a base block (XYXY, etc.).
+            total_sum = total_sum.wrapping_add(sum_exact_repeats_any_length(lo, hi, 2));
         }
 
-        fn sum_invalid_ids_in_range(lo: u128, hi: u128) -> u128 {
-            let mut total: u128 = 0;
-            let lo_s: String = lo.to_string();
-            let hi_s: String = hi.to_string();
-            let min_len: usize = lo_s.len();
-            let max_len: usize = hi_s.len();
-
-            // Iterate over total length D
-            for d in min_len..=max_len {
-                // Check if this length overlaps with [lo, hi]
-                // Smallest number with length d: 10^(d-1)
-                // Largest number with length d: 10^d - 1
-
-                // Optimization: Primes of d
-                let primes: Vec<u32> = prime_factors(d as u32);
-
-                if primes.is_empty() {
-                    // Length 1 has no prime factors, no period <= 1/2 (since 1/2 < 1).
-                    continue;
-                }
+        Ok(Answer::from(total_sum))
+    }
 
-                // Inclusion-Exclusion Principle
-                // Union of properties P_p: periodic with base length d/p
-                // We iterate over non-empty subsets of primes.
-                let n_primes: usize = primes.len();
-                let n_subsets: i32 = 1 << n_primes;
-
-                for i in 1..n_subsets {
-                    let mut subset_lcm: u32 = 1;
-                    let mut subset_size: i32 = 0;
-
-                    for bit in 0..n_primes {
-                        if (i >> bit) & 1 == 1 {
-                            subset_lcm = lcm(subset_lcm, primes[bit]);
-                            subset_size += 1;
-                        }
-                    }
-
-                    // This subset corresponds to numbers that can be represented as repeating
-                    // a block of size D/subset_lcm exactly `subset_lcm` times.
-                    let term: u128 = sum_exact_repeats_for_length(lo, hi, d as u32, subset_lcm);
-
-                    if subset_size % 2 == 1 {
-                        // Add
-                        total = total.wrapping_add(term);
-                    } else {
-                        // Subtract
-                        total = total.wrapping_sub(term);
-                    }
-                }
-            }
+    // For Part 2, return the sum of invalid IDs.
+    // An ID is invalid if it is made only of some sequence of digits repeated at least twice.
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut total_sum: u128 = 0;
 
-            total
+        for &(lo, hi) in parsed {
+            total_sum = total_sum.wrapping_add(sum_invalid_ids_in_range(lo, hi));
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        Ok(Answer::from(total_sum))
     }
 }
@@ -1,62 +1,11 @@
 use super::Day02;
-use aoc_core::{Solver, read_input};
+use aoc_core::{Answer, SolveError, Solver, check_examples};
 
 // Tests for Day 2 — based on the puzzle README and the provided input file.
 
 #[test]
-fn test_day02_readme_example_single_both_parts() {
-    // The README example (wrapped there for legibility) as a single line of ranges.
-    // It should produce a sum of 33 for Part 1.
-    let input: &str = "11-22";
-
-    let solver = Day02;
-    let result: Vec<String> = solver.solve(input);
-
-    // Part 1 example answer from README
-    assert_eq!(result[0], "33");
-
-    // Part 2 answer derived from logic or manual calculation if small enough.
-    // The README says for the FULL example it is 4174379265.
-    // For the single example "11-22":
-    // 11-22. Invalid: 11, 22. Sum = 33.
-    // Part 2 rule: "at least twice".
-    // 11 is 1 repeated 2 times. Valid.
-    // 22 is 2 repeated 2 times. Valid.
-    // So sum is 33.
-    assert_eq!(result[1], "33");
-}
-
-#[test]
-fn test_day02_readme_example_full_both_parts() {
-    // The README example (wrapped there for legibility) as a single line of ranges.
-    // It should produce a sum of 1227775554 for Part 1.
-    let input: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
-1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
-824824821-824824827,2121212118-2121212124";
-
-    let solver = Day02;
-    let result: Vec<String> = solver.solve(input);
-
-    // Part 1 example answer from README
-    assert_eq!(result[0], "1227775554");
-
-    // Part 2 example answer from README
-    assert_eq!(result[1], "4174379265");
-}
-
-#[test]
-fn test_day02_real_input_known_answers() {
-    // Smoke test for the real input file to quickly verify integration using
-    // the known correct answers for both parts.
-    let input: String = read_input(2025, 2).expect("input file should exist for day 02");
-    let solver = Day02;
-    let result: Vec<String> = solver.solve(&input);
-
-    // Ensure we always return two parts
-    assert_eq!(result.len(), 2, "solver should return two answers");
-
-    assert_eq!(result[0], "53420042388");
-    assert_eq!(result[1], "69553832684");
+fn test_day02_readme_examples() {
+    check_examples(&Day02, 2025, 2);
 }
 
 // --- Additional Day 2 tests -------------------------------------------------
@@ -67,7 +16,7 @@ fn test_day02_real_input_known_answers() {
 fn test_day02_part1_additional_passing() {
     let solver = Day02;
     // From README: 95-115 has one invalid ID 99 for Part 1 => sum 99
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     assert_eq!(result[0], "99");
 }
 
@@ -75,7 +24,7 @@ fn test_day02_part1_additional_passing() {
 fn test_day02_part1_additional_checks() {
     let solver = Day02;
     // Sanity check: 95-115 has a single invalid ID (99) for Part 1
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     assert_eq!(result[0], "99");
 }
 
@@ -83,7 +32,7 @@ fn test_day02_part1_additional_checks() {
 fn test_day02_part2_additional_passing() {
     let solver = Day02;
     // From README Part 2: 95-115 has invalid IDs 99 and 111 => 99 + 111 = 210
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     assert_eq!(result[1], "210");
 }
 
@@ -91,14 +40,14 @@ fn test_day02_part2_additional_passing() {
 fn test_day02_part2_additional_checks() {
     let solver = Day02;
     // Sanity check: 95-115 has invalid IDs 99 and 111 for Part 2 => 210
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     assert_eq!(result[1], "210");
 }
 
 #[test]
 fn test_day02_combined_passing() {
     let solver = Day02;
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     assert_eq!(result[0], "99");
     assert_eq!(result[1], "210");
 }
@@ -106,8 +55,46 @@ fn test_day02_combined_passing() {
 #[test]
 fn test_day02_combined_additional_checks() {
     let solver = Day02;
-    let result: Vec<String> = solver.solve("95-115");
+    let result: [Answer; 2] = solver.solve("95-115").unwrap();
     // Additional combined check: verify both parts match expected values
     assert_eq!(result[0], "99");
     assert_eq!(result[1], "210");
 }
+
+#[test]
+fn test_day02_overlapping_ranges_count_separately() {
+    let solver = Day02;
+    // Each range is summed on its own, so IDs in both copies count twice
+    let result: [Answer; 2] = solver.solve("95-115,95-115").unwrap();
+    assert_eq!(result[0], "198");
+    assert_eq!(result[1], "420");
+}
+
+#[test]
+fn test_day02_malformed_range_reports_position() {
+    let solver: Day02 = Day02;
+    let err: SolveError = solver.parse("11-22,12-x,95-115").unwrap_err();
+
+    match err {
+        SolveError::Parse(e) => {
+            assert_eq!((e.line, e.column), (1, 10));
+            assert_eq!(e.found, "x");
+        }
+        other => panic!("expected a parse error, got {other}"),
+    }
+}
+
+#[test]
+fn test_day02_ranges_may_wrap_across_lines() {
+    let solver = Day02;
+    let result: [Answer; 2] = solver.solve("11-\n22,95\n-115\n").unwrap();
+    assert_eq!(result[0], "132");
+    assert_eq!(result[1], "243");
+}
+
+#[test]
+fn test_day02_token_without_dash_is_not_skipped() {
+    let solver: Day02 = Day02;
+    let err: SolveError = solver.parse("11-22,\nabc").unwrap_err();
+    assert!(matches!(err, SolveError::Parse(e) if e.line == 2 && e.column == 1));
+}
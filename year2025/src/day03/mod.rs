@@ -1,121 +1,118 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::{Answer, SolveError, Solver};
 
 pub struct Day03;
 
 impl Solver for Day03 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn part1(input: &str) -> u128 {
-            // For each line (bank), pick two digits in order to form the largest possible
-            // two-digit number, then sum across banks.
-            let mut total: u128 = 0;
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() {
-                    continue;
-                }
+    type Parsed = Vec<Vec<u8>>;
 
-                // Collect digits; ignore any non-digit just in case.
-                let digits: Vec<u8> = s
-                    .chars()
-                    .filter_map(|c: char| c.to_digit(10).map(|d: u32| d as u8))
-                    .collect();
+    // One bank of batteries per line, as a list of digits.
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let mut banks: Vec<Vec<u8>> = Vec::new();
 
-                if digits.len() < 2 {
-                    // Can't form a two-digit number from this bank.
-                    continue;
-                }
+        for line in input.lines() {
+            let s: &str = line.trim();
+            if s.is_empty() {
+                continue;
+            }
 
-                // Build suffix maximums of digits to quickly get the best ones to place after index i
-                let n: usize = digits.len();
-                let mut suffix_max: Vec<u8> = vec![0; n + 1];
-                // suffix_max[n] = 0 (no digits after the last index)
-                for i in (0..n).rev() {
-                    suffix_max[i] = suffix_max[i + 1].max(digits[i]);
-                }
+            // Collect digits; ignore any non-digit just in case.
+            let digits: Vec<u8> = s
+                .chars()
+                .filter_map(|c: char| c.to_digit(10).map(|d: u32| d as u8))
+                .collect();
 
-                let mut best: u16 = 0;
-                for i in 0..(n - 1) {
-                    let tens: u8 = digits[i];
-                    let ones: u8 = suffix_max[i + 1];
-                    let cand: u16 = (tens as u16) * 10 + (ones as u16);
-                    if cand > best {
-                        best = cand;
-                    }
-                }
+            banks.push(digits);
+        }
 
-                total += best as u128;
+        Ok(banks)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // For each line (bank), pick two digits in order to form the largest possible
+        // two-digit number, then sum across banks.
+        let mut total: u128 = 0;
+
+        for digits in parsed {
+            if digits.len() < 2 {
+                // Can't form a two-digit number from this bank.
+                continue;
             }
 
-            total
-        }
+            // Build suffix maximums of digits to quickly get the best ones to place after index i
+            let n: usize = digits.len();
+            let mut suffix_max: Vec<u8> = vec![0; n + 1];
+            // suffix_max[n] = 0 (no digits after the last index)
+            for i in (0..n).rev() {
+                suffix_max[i] = suffix_max[i + 1].max(digits[i]);
+            }
 
-        // For Part 2 (unspecified in README excerpt), return the count of invalid IDs.
-        fn part2(input: &str) -> u128 {
-            // For each line (bank), pick exactly 12 digits in order to form the
-            // lexicographically largest possible 12-digit number, then sum across banks.
-            const K: usize = 12;
-            let mut total: u128 = 0;
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() {
-                    continue;
+            let mut best: u16 = 0;
+            for i in 0..(n - 1) {
+                let tens: u8 = digits[i];
+                let ones: u8 = suffix_max[i + 1];
+                let cand: u16 = (tens as u16) * 10 + (ones as u16);
+                if cand > best {
+                    best = cand;
                 }
+            }
 
-                // Collect digits; ignore any non-digit just in case.
-                let digits: Vec<u8> = s
-                    .chars()
-                    .filter_map(|c| c.to_digit(10).map(|d: u32| d as u8))
-                    .collect();
+            total += best as u128;
+        }
 
-                let n: usize = digits.len();
+        Ok(Answer::from(total))
+    }
 
-                if n < K {
-                    // Cannot select exactly K digits; contribute 0.
-                    continue;
-                }
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // For each line (bank), pick exactly 12 digits in order to form the
+        // lexicographically largest possible 12-digit number, then sum across banks.
+        const K: usize = 12;
+        let mut total: u128 = 0;
 
-                // Greedy monotonic stack to keep the largest subsequence of length K.
-                // We can remove exactly r = n - K digits.
-                let mut remove: usize = n - K;
-                let mut stack: Vec<u8> = Vec::with_capacity(n);
-
-                for &d in &digits {
-                    while remove > 0 && !stack.is_empty() && stack[stack.len() - 1] < d {
-                        stack.pop();
-                        remove -= 1;
-                    }
-                    stack.push(d);
-                }
+        for digits in parsed {
+            let n: usize = digits.len();
 
-                // If we still have removals left, drop from the end.
-                let mut chosen: Vec<u8> = if stack.len() > K {
-                    stack[..K].to_vec()
-                } else {
-                    stack
-                };
+            if n < K {
+                // Cannot select exactly K digits; contribute 0.
+                continue;
+            }
 
-                if chosen.len() > K {
-                    chosen.truncate(K);
+            // Greedy monotonic stack to keep the largest subsequence of length K.
+            // We can remove exactly r = n - K digits.
+            let mut remove: usize = n - K;
+            let mut stack: Vec<u8> = Vec::with_capacity(n);
+
+            for &d in digits {
+                while remove > 0 && !stack.is_empty() && stack[stack.len() - 1] < d {
+                    stack.pop();
+                    remove -= 1;
                 }
+                stack.push(d);
+            }
 
-                // Convert the 12-digit sequence to a u128 value and add to the total.
-                let mut val: u128 = 0;
+            // If we still have removals left, drop from the end.
+            let mut chosen: Vec<u8> = if stack.len() > K {
+                stack[..K].to_vec()
+            } else {
+                stack
+            };
 
-                for &d in &chosen {
-                    val = val * 10 + (d as u128);
-                }
+            if chosen.len() > K {
+                chosen.truncate(K);
+            }
+
+            // Convert the 12-digit sequence to a u128 value and add to the total.
+            let mut val: u128 = 0;
 
-                total += val;
+            for &d in &chosen {
+                val = val * 10 + (d as u128);
             }
 
-            total
+            total += val;
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        Ok(Answer::from(total))
     }
 }
@@ -1,179 +1,84 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::{Answer, SolveError, Solver};
+use std::collections::VecDeque;
 
 pub struct Day04;
 
-impl Solver for Day04 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn part1(input: &str) -> u128 {
-            // Count how many rolls ('@') are accessible: a roll is accessible
-            // if it has fewer than four adjacent rolls among the 8 neighbors.
-            let mut grid: Vec<Vec<u8>> = Vec::new();
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() {
-                    continue;
-                }
-
-                let row: Vec<u8> = s
-                    .chars()
-                    .map(|c: char| if c == '@' { 1u8 } else { 0u8 })
-                    .collect();
-                grid.push(row);
-            }
-
-            let h: usize = grid.len();
-            if h == 0 {
-                return 0u128;
-            }
-            let w: usize = grid[0].len();
-
-            let dirs: [(isize, isize); 8] = [
-                (-1, -1), (0, -1), (1, -1),
-                (-1,  0),          (1,  0),
-                (-1,  1), (0,  1), (1,  1),
-            ];
-
-            let mut total: u128 = 0u128;
-
-            for y in 0..h {
-                for x in 0..w {
-                    if grid[y][x] == 0u8 {
-                        continue;
-                    }
-
-                    let mut adj: u8 = 0u8;
-                    for &(dx, dy) in &dirs {
-                        let nx: isize = x as isize + dx;
-                        let ny: isize = y as isize + dy;
-                        if nx >= 0 && ny >= 0 {
-                            let ux: usize = nx as usize;
-                            let uy: usize = ny as usize;
-                            if uy < h && ux < w {
-                                adj = adj.saturating_add(grid[uy][ux]);
-                            }
-                        }
-                    }
-
-                    if adj < 4u8 {
-                        total = total + 1u128;
-                    }
-                }
-            }
-
-            total
-        }
-
-        fn part2(input: &str) -> u128 {
-            // Repeatedly remove accessible rolls ('@'): a roll is accessible
-            // if it has fewer than four adjacent rolls among the 8 neighbors.
-            // After removing a roll, neighbors' adjacency counts decrease,
-            // potentially making more rolls accessible. Return total removed.
-            use std::collections::VecDeque;
-
-            let mut grid: Vec<Vec<u8>> = Vec::new();
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() {
-                    continue;
-                }
+// The number of neighbouring rolls each roll has, 0 where there is no roll.
+fn adjacent_rolls(grid: &Grid<bool>) -> Grid<u8> {
+    let mut adj: Grid<u8> = Grid::new(grid.width(), grid.height(), 0u8);
+    for pos in grid.find_all(|&roll: &bool| roll) {
+        adj[pos] = grid.neighbors8(pos).filter(|&n: &Pos| grid[n]).count() as u8;
+    }
+    adj
+}
 
-                let row: Vec<u8> = s
-                    .chars()
-                    .map(|c: char| if c == '@' { 1u8 } else { 0u8 })
-                    .collect();
-                grid.push(row);
-            }
+impl Solver for Day04 {
+    type Parsed = Grid<bool>;
+
+    // The grid of paper rolls: true where there is a roll ('@').
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let grid: Grid<bool> = Grid::parse(input, |c: char| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("`@` or `.`"),
+        })?;
+        Ok(grid)
+    }
 
-            let h: usize = grid.len();
-            if h == 0 {
-                return 0u128;
-            }
-            let w: usize = grid[0].len();
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // Count how many rolls ('@') are accessible: a roll is accessible
+        // if it has fewer than four adjacent rolls among the 8 neighbors.
+        let grid: &Grid<bool> = parsed;
+        let adj: Grid<u8> = adjacent_rolls(grid);
 
-            let dirs: [(isize, isize); 8] = [
-                (-1, -1), (0, -1), (1, -1),
-                (-1,  0),          (1,  0),
-                (-1,  1), (0,  1), (1,  1),
-            ];
+        let total: u128 = grid
+            .find_all(|&roll: &bool| roll)
+            .filter(|&pos: &Pos| adj[pos] < 4u8)
+            .count() as u128;
 
-            // Precompute adjacency counts for all cells.
-            let mut adj: Vec<Vec<u8>> = vec![vec![0u8; w]; h];
-            for y in 0..h {
-                for x in 0..w {
-                    if grid[y][x] == 0u8 {
-                        continue;
-                    }
-                    let mut cnt: u8 = 0u8;
-                    for &(dx, dy) in &dirs {
-                        let nx: isize = x as isize + dx;
-                        let ny: isize = y as isize + dy;
-                        if nx >= 0 && ny >= 0 {
-                            let ux: usize = nx as usize;
-                            let uy: usize = ny as usize;
-                            if uy < h && ux < w {
-                                cnt = cnt.saturating_add(grid[uy][ux]);
-                            }
-                        }
-                    }
-                    adj[y][x] = cnt;
-                }
-            }
+        Ok(Answer::from(total))
+    }
 
-            // Initialize queue with currently-accessible rolls (adj < 4).
-            let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
-            for y in 0..h {
-                for x in 0..w {
-                    if grid[y][x] == 1u8 && adj[y][x] < 4u8 {
-                        queue.push_back((x, y));
-                    }
-                }
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // Repeatedly remove accessible rolls ('@'): a roll is accessible
+        // if it has fewer than four adjacent rolls among the 8 neighbors.
+        // After removing a roll, neighbors' adjacency counts decrease,
+        // potentially making more rolls accessible. Return total removed.
+        let mut grid: Grid<bool> = parsed.clone();
+        let mut adj: Grid<u8> = adjacent_rolls(&grid);
+
+        // Initialize queue with currently-accessible rolls (adj < 4).
+        let mut queue: VecDeque<Pos> = grid
+            .find_all(|&roll: &bool| roll)
+            .filter(|&pos: &Pos| adj[pos] < 4u8)
+            .collect();
+
+        let mut removed_total: u128 = 0u128;
+
+        while let Some(pos) = queue.pop_front() {
+            // Skip if already removed or no longer accessible.
+            if !grid[pos] || adj[pos] >= 4u8 {
+                continue;
             }
 
-            let mut removed_total: u128 = 0u128;
-
-            while let Some((x, y)) = queue.pop_front() {
-                // Skip if already removed or no longer accessible.
-                if grid[y][x] == 0u8 {
-                    continue;
-                }
-                if adj[y][x] >= 4u8 {
-                    continue;
-                }
+            // Remove this roll.
+            grid[pos] = false;
+            removed_total += 1u128;
 
-                // Remove this roll.
-                grid[y][x] = 0u8;
-                removed_total = removed_total + 1u128;
-
-                // Update neighbors' adjacency counts and enqueue if they become accessible.
-                for &(dx, dy) in &dirs {
-                    let nx: isize = x as isize + dx;
-                    let ny: isize = y as isize + dy;
-                    if nx >= 0 && ny >= 0 {
-                        let ux: usize = nx as usize;
-                        let uy: usize = ny as usize;
-                        if uy < h && ux < w {
-                            if grid[uy][ux] == 1u8 {
-                                // This neighbor loses one adjacent roll.
-                                if adj[uy][ux] > 0u8 {
-                                    adj[uy][ux] -= 1u8;
-                                }
-                                if adj[uy][ux] < 4u8 {
-                                    queue.push_back((ux, uy));
-                                }
-                            }
-                        }
-                    }
+            // Update neighbors' adjacency counts and enqueue if they become accessible.
+            for n in grid.neighbors8(pos).filter(|&n: &Pos| grid[n]) {
+                // This neighbor loses one adjacent roll.
+                adj[n] = adj[n].saturating_sub(1u8);
+                if adj[n] < 4u8 {
+                    queue.push_back(n);
                 }
             }
-
-            removed_total
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        Ok(Answer::from(removed_total))
     }
 }
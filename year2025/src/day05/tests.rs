@@ -1,52 +1,48 @@
-use super::Day05;
-use aoc_core::{Solver, read_input};
+use super::{Database, Day05};
+use aoc_core::{SolveError, Solver, check_examples};
 
 #[test]
-fn test_day05_readme_example_part1() {
-    // Example from README.md
-    let input: &str = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+fn test_day05_readme_examples() {
+    check_examples(&Day05, 2025, 5);
+}
 
+#[test]
+fn test_day05_malformed_range_reports_position() {
     let solver: Day05 = Day05;
-    let result: Vec<String> = solver.solve(input);
-
-    // Part 1: three of the IDs are fresh
-    assert_eq!(result[0], "3");
+    let err: SolveError = solver.parse("3-5\n10-1x4\n\n1").unwrap_err();
+
+    match err {
+        SolveError::Parse(e) => {
+            assert_eq!((e.line, e.column), (2, 4));
+            assert_eq!(e.found, "1x4");
+        }
+        other => panic!("expected a parse error, got {other}"),
+    }
 }
 
 #[test]
-fn test_day05_readme_example_part2() {
-    // Same README example; Part 2 counts total IDs covered by the union of ranges.
-    let input: &str = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-
+fn test_day05_line_without_dash_is_not_skipped() {
     let solver: Day05 = Day05;
-    let result: Vec<String> = solver.solve(input);
-
-    // Part 2: union is {3..=5} plus {10..=20} => 3 + 11 = 14
-    assert_eq!(result[1], "14");
+    // A truncated range line used to be ignored silently.
+    let err: SolveError = solver.parse("3-5\n12\n\n1").unwrap_err();
+    assert!(matches!(err, SolveError::Parse(e) if e.line == 2 && e.column == 1));
 }
 
 #[test]
-fn test_day05_real_input_known_answer_part1() {
-    let input: String = read_input(2025, 5).expect("input file should exist for day 05");
+fn test_day05_malformed_id_reports_position() {
     let solver: Day05 = Day05;
-    let result: Vec<String> = solver.solve(&input);
-
-    // Ensure two parts are returned
-    assert_eq!(result.len(), 2, "solver should return two answers");
-
-    // Known correct Part 1 answer (computed via runner)
-    assert_eq!(result[0], "674");
+    let err: SolveError = solver.parse("3-5\n\n1\n  5a").unwrap_err();
+    assert!(matches!(err, SolveError::Parse(e) if e.line == 4 && e.column == 3));
 }
 
 #[test]
-fn test_day05_real_input_known_answer_part2() {
-    let input: String = read_input(2025, 5).expect("input file should exist for day 05");
+fn test_day05_every_id_fresh_is_an_error() {
     let solver: Day05 = Day05;
-    let result: Vec<String> = solver.solve(&input);
-
-    // Ensure two parts are returned
-    assert_eq!(result.len(), 2, "solver should return two answers");
-
-    // Known correct Part 2 answer (computed via runner)
-    assert_eq!(result[1], "352509891817881");
+    let parsed: Database = solver
+        .parse("0-340282366920938463463374607431768211455\n\n1")
+        .unwrap();
+    assert!(matches!(
+        solver.part2(&parsed),
+        Err(SolveError::InvalidInput(_))
+    ));
 }
@@ -1,139 +1,108 @@
 #[cfg(test)]
 mod tests;
 
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::{Answer, SolveError, Solver};
 use std::collections::HashSet;
-use aoc_core::Solver;
 
 pub struct Day07;
 
+pub struct Manifold {
+    grid: Grid<char>,
+    start: Option<Pos>,
+}
+
 impl Solver for Day07 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn part1(input: &str) -> u128 {
-            let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
-            if lines.is_empty() {
-                return 0;
-            }
+    type Parsed = Manifold;
 
-            let height = lines.len();
-            let width = lines[0].len();
-            let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-
-            // Find start
-            let mut start_pos = None;
-            for r in 0..height {
-                for c in 0..width {
-                    if grid[r][c] == 'S' {
-                        start_pos = Some((r, c));
-                        break;
-                    }
-                }
-                if start_pos.is_some() {
-                    break;
-                }
-            }
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let grid: Grid<char> = Grid::parse(input, |c: char| match c {
+            '.' | 'S' | '^' => Ok(c),
+            _ => Err("`.`, `S` or `^`"),
+        })?;
+        let start: Option<Pos> = grid.find(|&c: &char| c == 'S');
 
-            let (start_r, start_c) = match start_pos {
-                Some(pos) => pos,
-                None => return 0,
-            };
-
-            let mut active_cols: HashSet<isize> = HashSet::new();
-            active_cols.insert(start_c as isize);
-            
-            let mut splits: u128 = 0;
-
-            for r in (start_r + 1)..height {
-                let mut next_cols: HashSet<isize> = HashSet::new();
-                
-                for &c in &active_cols {
-                    // Check bounds
-                    if c < 0 || c >= width as isize {
-                        continue;
-                    }
-                    
-                    let char_at = grid[r][c as usize];
-                    if char_at == '^' {
-                        splits += 1;
-                        next_cols.insert(c - 1);
-                        next_cols.insert(c + 1);
-                    } else {
-                        next_cols.insert(c);
-                    }
-                }
-                
-                active_cols = next_cols;
-                
-                if active_cols.is_empty() {
-                    break;
+        Ok(Manifold { grid, start })
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let Manifold { grid, start } = parsed;
+
+        let (start_c, start_r) = match *start {
+            Some(pos) => pos,
+            None => return Ok(Answer::from(0)),
+        };
+
+        let mut active_cols: HashSet<usize> = HashSet::new();
+        active_cols.insert(start_c);
+
+        let mut splits: u128 = 0;
+
+        for row in grid.rows().skip(start_r + 1) {
+            let mut next_cols: HashSet<usize> = HashSet::new();
+
+            for &c in &active_cols {
+                if row[c] == '^' {
+                    splits += 1;
+                    // Beams leaving the manifold sideways are gone.
+                    next_cols.extend(c.checked_sub(1));
+                    next_cols.extend(Some(c + 1).filter(|&c: &usize| c < grid.width()));
+                } else {
+                    next_cols.insert(c);
                 }
             }
 
-            splits
-        }
+            active_cols = next_cols;
 
-        fn part2(input: &str) -> u128 {
-            let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
-            if lines.is_empty() {
-                return 0;
+            if active_cols.is_empty() {
+                break;
             }
+        }
 
-            let height = lines.len();
-            let width = lines[0].len();
-            let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-
-            // Find start
-            let mut start_pos = None;
-            for r in 0..height {
-                for c in 0..width {
-                    if grid[r][c] == 'S' {
-                        start_pos = Some((r, c));
-                        break;
-                    }
-                }
-                if start_pos.is_some() {
-                    break;
+        Ok(Answer::from(splits))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        let Manifold { grid, start } = parsed;
+
+        let width = grid.width();
+
+        let (start_c, start_r) = match *start {
+            Some(pos) => pos,
+            None => return Ok(Answer::from(0)),
+        };
+
+        let mut counts: Vec<u128> = vec![0; width];
+        counts[start_c] = 1;
+
+        for row in grid.rows().skip(start_r + 1) {
+            let mut next_counts: Vec<u128> = vec![0; width];
+
+            for c in 0..width {
+                let count = counts[c];
+                if count == 0 {
+                    continue;
                 }
-            }
 
-            let (start_r, start_c) = match start_pos {
-                Some(pos) => pos,
-                None => return 0,
-            };
-
-            let mut counts: Vec<u128> = vec![0; width];
-            counts[start_c] = 1;
-
-            for r in (start_r + 1)..height {
-                let mut next_counts: Vec<u128> = vec![0; width];
-                
-                for c in 0..width {
-                    let count = counts[c];
-                    if count == 0 {
-                        continue;
+                let char_at = row[c];
+                if char_at == '^' {
+                    if c > 0 {
+                        next_counts[c - 1] += count;
                     }
-                    
-                    let char_at = grid[r][c];
-                    if char_at == '^' {
-                        if c > 0 {
-                            next_counts[c - 1] += count;
-                        }
-                        if c < width - 1 {
-                            next_counts[c + 1] += count;
-                        }
-                    } else {
-                        next_counts[c] += count;
+                    if c < width - 1 {
+                        next_counts[c + 1] += count;
                     }
-                }
-                
-                counts = next_counts;
-                if counts.iter().all(|&x| x == 0) {
-                    break;
+                } else {
+                    next_counts[c] += count;
                 }
             }
 
-            counts.iter().sum()
+            counts = next_counts;
+            if counts.iter().all(|&x| x == 0) {
+                break;
+            }
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        Ok(Answer::from(counts.iter().sum::<u128>()))
     }
 }
@@ -1,50 +1,7 @@
 use super::Day07;
-use aoc_core::Solver;
+use aoc_core::check_examples;
 
 #[test]
-fn test_part1_readme_example() {
-    let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-    
-    let solver = Day07;
-    let result = solver.solve(input);
-    assert_eq!(result[0], "21");
-}
-
-#[test]
-fn test_part2_readme_example() {
-    let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-    
-    let solver = Day07;
-    let result = solver.solve(input);
-    assert_eq!(result[1], "40");
+fn test_day07_readme_examples() {
+    check_examples(&Day07, 2025, 7);
 }
@@ -1,198 +1,119 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::collections::UnionFind;
+use aoc_core::error::{ParseError, parse_token};
+use aoc_core::{Answer, SolveError, Solver};
 
 pub struct Day08;
 
-impl Solver for Day08 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn part1(input: &str) -> u128 {
-            // Parse 3D points and connect the K closest pairs (K depends on input size),
-            // then multiply the sizes of the three largest resulting circuits (components).
-            let mut points: Vec<(i128, i128, i128)> = Vec::new();
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() { continue; }
-                // Expect format: X,Y,Z
-                let mut it = s.split(',');
-                let x_str: &str = it.next().expect("missing x");
-                let y_str: &str = it.next().expect("missing y");
-                let z_str: &str = it.next().expect("missing z");
-                let x: i128 = x_str.trim().parse::<i128>().expect("invalid x");
-                let y: i128 = y_str.trim().parse::<i128>().expect("invalid y");
-                let z: i128 = z_str.trim().parse::<i128>().expect("invalid z");
-                points.push((x, y, z));
-            }
+#[derive(Debug)]
+pub struct Playground {
+    points: Vec<(i128, i128, i128)>,
+    pairs: Vec<(u128, usize, usize)>,
+}
 
-            let n: usize = points.len();
-            if n == 0 { return 0u128; }
-
-            // Heuristic: the README example uses 10 connections for 20 points; the real input uses 1000.
-            let k_target: usize = if n <= 20usize { 10usize } else { 1000usize };
-
-            // Build all pair distances (squared Euclidean) as (dist, i, j).
-            let mut pairs: Vec<(u128, usize, usize)> = Vec::new();
-            pairs.reserve(n.saturating_mul(n.saturating_sub(1usize)) / 2usize);
-            for i in 0..n {
-                let (xi, yi, zi) = points[i];
-                for j in (i + 1)..n {
-                    let (xj, yj, zj) = points[j];
-                    let dx: i128 = xj - xi;
-                    let dy: i128 = yj - yi;
-                    let dz: i128 = zj - zi;
-                    let dist2: u128 = (dx * dx) as u128 + (dy * dy) as u128 + (dz * dz) as u128;
-                    pairs.push((dist2, i, j));
+impl Solver for Day08 {
+    type Parsed = Playground;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let mut points: Vec<(i128, i128, i128)> = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let s: &str = line.trim();
+            if s.is_empty() { continue; }
+            // Expect format: X,Y,Z
+            let mut it = s.split(',');
+            let mut coordinate = |expected: &str| -> Result<i128, ParseError> {
+                match it.next() {
+                    Some(tok) => parse_token(i, line, tok.trim(), expected),
+                    None => Err(ParseError::end_of_line(i, line, expected)),
                 }
+            };
+            let x: i128 = coordinate("an integer X coordinate")?;
+            let y: i128 = coordinate("an integer Y coordinate")?;
+            let z: i128 = coordinate("an integer Z coordinate")?;
+            if let Some(extra) = it.next() {
+                return Err(ParseError::new(i, line, extra, "end of line after `X,Y,Z`").into());
             }
+            points.push((x, y, z));
+        }
 
-            // Select the k smallest distances.
-            let m: usize = pairs.len();
-            let k_use: usize = k_target.min(m);
-            if k_use < m {
-                let nth_idx: usize = if k_use == 0 { 0 } else { k_use - 1usize };
-                pairs.select_nth_unstable_by(nth_idx, |a: &(u128, usize, usize), b: &(u128, usize, usize)| a.0.cmp(&b.0));
-                // Sort only the first k_use by distance ascending to ensure deterministic unions.
-                pairs[..k_use].sort_unstable_by(|a: &(u128, usize, usize), b: &(u128, usize, usize)| a.0.cmp(&b.0));
-                pairs.truncate(k_use);
-            } else {
-                pairs.sort_unstable_by(|a: &(u128, usize, usize), b: &(u128, usize, usize)| a.0.cmp(&b.0));
+        // Build all pair distances (squared Euclidean) as (dist, i, j), sorted by distance,
+        // then by indices for deterministic behavior. Both parts walk this list in order.
+        let n: usize = points.len();
+        let mut pairs: Vec<(u128, usize, usize)> =
+            Vec::with_capacity(n.saturating_mul(n.saturating_sub(1usize)) / 2usize);
+        for (i, &(xi, yi, zi)) in points.iter().enumerate() {
+            for (j, &(xj, yj, zj)) in points.iter().enumerate().skip(i + 1usize) {
+                let dx: i128 = xj - xi;
+                let dy: i128 = yj - yi;
+                let dz: i128 = zj - zi;
+                let dist2: u128 = (dx * dx) as u128 + (dy * dy) as u128 + (dz * dz) as u128;
+                pairs.push((dist2, i, j));
             }
+        }
+        pairs.sort_unstable();
 
-            // Disjoint Set Union (Union-Find) with size tracking.
-            let mut parent: Vec<usize> = (0..n).collect();
-            let mut size: Vec<u128> = vec![1u128; n];
-
-            fn find(parent: &mut [usize], x: usize) -> usize {
-                let mut v: usize = x;
-                while parent[v] != v {
-                    let p: usize = parent[v];
-                    let gp: usize = parent[p];
-                    parent[v] = gp; // path halving
-                    v = p;
-                }
-                v
-            }
+        Ok(Playground { points, pairs })
+    }
 
-            let mut unions_done: usize = 0usize;
-            for &(_d, i, j) in &pairs {
-                if unions_done >= k_use { break; }
-                let mut ri: usize = find(&mut parent, i);
-                let mut rj: usize = find(&mut parent, j);
-                if ri == rj { continue; }
-                // Union by size
-                if size[ri] < size[rj] { core::mem::swap(&mut ri, &mut rj); }
-                parent[rj] = ri;
-                size[ri] = size[ri] + size[rj];
-                unions_done = unions_done + 1usize;
-            }
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // Connect the K closest pairs (K depends on input size), then multiply
+        // the sizes of the three largest resulting circuits (components).
+        let Playground { points, pairs } = parsed;
 
-            // Collect component sizes at roots
-            let mut comps: Vec<u128> = Vec::new();
-            for i in 0..n {
-                if find(&mut parent, i) == i {
-                    comps.push(size[i]);
-                }
-            }
-            if comps.is_empty() { return 0u128; }
-            comps.sort_unstable_by(|a: &u128, b: &u128| b.cmp(a)); // descending
+        let n: usize = points.len();
+        if n == 0 { return Ok(Answer::from(0u128)); }
 
-            let mut product: u128 = 1u128;
-            let take: usize = comps.len().min(3usize);
-            for idx in 0..take {
-                product = product * comps[idx];
-            }
-            product
-        }
+        // Heuristic: the README example uses 10 connections for 20 points; the real input uses 1000.
+        let k_target: usize = if n <= 20usize { 10usize } else { 1000usize };
 
-        fn part2(input: &str) -> u128 {
-            // Keep connecting the closest unconnected pairs until all junction boxes
-            // are in one circuit. Return the product of the X coordinates of the
-            // last two junction boxes connected.
-            let mut points: Vec<(i128, i128, i128)> = Vec::new();
-
-            for line in input.lines() {
-                let s: &str = line.trim();
-                if s.is_empty() { continue; }
-                let mut it = s.split(',');
-                let x_str: &str = it.next().expect("missing x");
-                let y_str: &str = it.next().expect("missing y");
-                let z_str: &str = it.next().expect("missing z");
-                let x: i128 = x_str.trim().parse::<i128>().expect("invalid x");
-                let y: i128 = y_str.trim().parse::<i128>().expect("invalid y");
-                let z: i128 = z_str.trim().parse::<i128>().expect("invalid z");
-                points.push((x, y, z));
-            }
+        // Take the k smallest distances.
+        let k_use: usize = k_target.min(pairs.len());
+        let pairs: &[(u128, usize, usize)] = &pairs[..k_use];
 
-            let n: usize = points.len();
-            if n == 0usize { return 0u128; }
-            if n == 1usize { return points[0].0 as u128; }
-
-            // Build all pair distances (squared Euclidean) as (dist, i, j).
-            let mut pairs: Vec<(u128, usize, usize)> = Vec::new();
-            pairs.reserve(n.saturating_mul(n.saturating_sub(1usize)) / 2usize);
-            for i in 0..n {
-                let (xi, yi, zi) = points[i];
-                for j in (i + 1usize)..n {
-                    let (xj, yj, zj) = points[j];
-                    let dx: i128 = xj - xi;
-                    let dy: i128 = yj - yi;
-                    let dz: i128 = zj - zi;
-                    let dist2: u128 = (dx * dx) as u128 + (dy * dy) as u128 + (dz * dz) as u128;
-                    pairs.push((dist2, i, j));
-                }
-            }
+        let mut circuits: UnionFind = UnionFind::new(n);
+        for &(_d, i, j) in pairs {
+            circuits.union(i, j);
+        }
 
-            // Sort pairs by distance, then by indices for deterministic behavior.
-            pairs.sort_unstable_by(|a: &(u128, usize, usize), b: &(u128, usize, usize)| {
-                match a.0.cmp(&b.0) {
-                    core::cmp::Ordering::Equal => match a.1.cmp(&b.1) {
-                        core::cmp::Ordering::Equal => a.2.cmp(&b.2),
-                        other => other,
-                    },
-                    other => other,
-                }
-            });
-
-            // DSU setup
-            let mut parent: Vec<usize> = (0usize..n).collect();
-            let mut size: Vec<u128> = vec![1u128; n];
-
-            fn find(parent: &mut [usize], x: usize) -> usize {
-                let mut v: usize = x;
-                while parent[v] != v {
-                    let p: usize = parent[v];
-                    let gp: usize = parent[p];
-                    parent[v] = gp; // path halving
-                    v = p;
-                }
-                v
-            }
+        // Sizes of the resulting circuits.
+        let mut comps: Vec<u128> =
+            circuits.components().map(|c: Vec<usize>| c.len() as u128).collect();
+        if comps.is_empty() { return Ok(Answer::from(0u128)); }
+        comps.sort_unstable_by(|a: &u128, b: &u128| b.cmp(a)); // descending
 
-            let mut comps: usize = n;
-            let mut last_i: usize = 0usize;
-            let mut last_j: usize = 0usize;
-
-            for &(_d, i, j) in &pairs {
-                let mut ri: usize = find(&mut parent, i);
-                let mut rj: usize = find(&mut parent, j);
-                if ri == rj { continue; }
-                // Union by size
-                if size[ri] < size[rj] { core::mem::swap(&mut ri, &mut rj); }
-                parent[rj] = ri;
-                size[ri] = size[ri] + size[rj];
-                comps = comps - 1usize;
-                last_i = i;
-                last_j = j;
-                if comps == 1usize { break; }
-            }
+        let mut product: u128 = 1u128;
+        for &component_size in comps.iter().take(3usize) {
+            product *= component_size;
+        }
+        Ok(Answer::from(product))
+    }
 
-            let xi: u128 = if points[last_i].0 >= 0 { points[last_i].0 as u128 } else { 0u128 };
-            let xj: u128 = if points[last_j].0 >= 0 { points[last_j].0 as u128 } else { 0u128 };
-            xi * xj
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        // Keep connecting the closest unconnected pairs until all junction boxes
+        // are in one circuit. Return the product of the X coordinates of the
+        // last two junction boxes connected.
+        let Playground { points, pairs } = parsed;
+
+        let n: usize = points.len();
+        if n == 0usize { return Ok(Answer::from(0u128)); }
+        if n == 1usize { return Ok(Answer::from(points[0].0 as u128)); }
+
+        let mut circuits: UnionFind = UnionFind::new(n);
+        let mut last_i: usize = 0usize;
+        let mut last_j: usize = 0usize;
+
+        for &(_d, i, j) in pairs {
+            if !circuits.union(i, j) { continue; }
+            last_i = i;
+            last_j = j;
+            if circuits.count() == 1usize { break; }
         }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+        let xi: u128 = if points[last_i].0 >= 0 { points[last_i].0 as u128 } else { 0u128 };
+        let xj: u128 = if points[last_j].0 >= 0 { points[last_j].0 as u128 } else { 0u128 };
+        Ok(Answer::from(xi * xj))
     }
 }
@@ -1,54 +1,31 @@
 use super::Day09;
-use aoc_core::{Solver, read_input};
+use aoc_core::{SolveError, Solver, check_examples};
 
 // Tests for Day 9 — based on the puzzle README and the provided input file.
 
 #[test]
-fn test_day09_readme_example_part1() {
-    // Example from README.md
-    let input: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
-
-    let solver: Day09 = Day09;
-    let result: Vec<String> = solver.solve(input);
-
-    // README states the largest rectangle area is 50
-    assert_eq!(result[0], "50");
+fn test_day09_readme_examples() {
+    check_examples(&Day09, 2025, 9);
 }
 
 #[test]
-fn test_day09_real_input_known_answer_part1() {
-    let input: String = read_input(2025, 9).expect("input file should exist for day 09");
+fn test_day09_point_without_comma_is_reported() {
     let solver: Day09 = Day09;
-    let result: Vec<String> = solver.solve(&input);
-
-    // Ensure two parts are returned
-    assert_eq!(result.len(), 2, "solver should return two answers");
-
-    // Known correct Part 1 answer (computed via runner)
-    assert_eq!(result[0], "4759930955");
+    // Previously a line without a comma was dropped without a word.
+    let err: SolveError = solver.parse("7,1\n11 1\n11,7").unwrap_err();
+    assert!(matches!(err, SolveError::Parse(e) if e.line == 2 && e.found == "11 1"));
 }
 
 #[test]
-fn test_day09_readme_example_part2() {
-    // Example from README.md (same red tile list)
-    let input: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
-
+fn test_day09_invalid_coordinate_reports_column() {
     let solver: Day09 = Day09;
-    let result: Vec<String> = solver.solve(input);
-
-    // README: largest rectangle using only red+green tiles has area 24
-    assert_eq!(result[1], "24");
+    let err: SolveError = solver.parse("7,1\n11,-\n").unwrap_err();
+    assert!(matches!(err, SolveError::Parse(e) if e.line == 2 && e.column == 4));
 }
 
 #[test]
-fn test_day09_real_input_known_answer_part2() {
-    let input: String = read_input(2025, 9).expect("input file should exist for day 09");
+fn test_day09_slanted_edge_is_an_error() {
     let solver: Day09 = Day09;
-    let result: Vec<String> = solver.solve(&input);
-
-    // Ensure two parts are returned
-    assert_eq!(result.len(), 2, "solver should return two answers");
-
-    // Known correct Part 2 answer (computed via runner)
-    assert_eq!(result[1], "1525241870");
+    let parsed: Vec<(i128, i128)> = solver.parse("7,1\n11,7\n7,7").unwrap();
+    assert!(matches!(solver.part2(&parsed), Err(SolveError::InvalidInput(_))));
 }
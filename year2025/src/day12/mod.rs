@@ -1,20 +1,24 @@
 #[cfg(test)]
 mod tests;
 
-use aoc_core::Solver;
+use aoc_core::{Answer, SolveError, Solver};
 
 pub struct Day12;
 
 impl Solver for Day12 {
-    fn solve(&self, input: &str) -> Vec<String> {
-        fn part1(input: &str) -> u128 {
-            123
-        }
+    type Parsed = ();
+    const PARSES: bool = false;
+    const IMPLEMENTED: bool = false;
 
-        fn part2(input: &str) -> u128 {
-            123
-        }
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(())
+    }
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
 
-        vec![part1(input).to_string(), part2(input).to_string()]
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
     }
 }
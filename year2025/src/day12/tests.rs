@@ -1,2 +1,13 @@
 use super::Day12;
 use aoc_core::{Solver, read_input};
+
+// Tests for Day 12 — based on the puzzle README and the provided input file.
+
+#[test]
+fn test_day12_real_input_structure() {
+    let input: String = read_input(2025, 12).expect("input file should exist for day 12");
+    let solver: Day12 = Day12;
+
+    // The parts are not implemented yet, but the real input must already parse
+    assert!(solver.parse(&input).is_ok(), "real input should parse");
+}
@@ -1,32 +1,15 @@
-use aoc_core::Solver;
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-
-pub fn get_solver(day: u8) -> Option<Box<dyn Solver>> {
-    match day {
-        1 => Some(Box::new(day01::Day01)),
-        2 => Some(Box::new(day02::Day02)),
-        3 => Some(Box::new(day03::Day03)),
-        4 => Some(Box::new(day04::Day04)),
-        5 => Some(Box::new(day05::Day05)),
-        6 => Some(Box::new(day06::Day06)),
-        7 => Some(Box::new(day07::Day07)),
-        8 => Some(Box::new(day08::Day08)),
-        9 => Some(Box::new(day09::Day09)),
-        10 => Some(Box::new(day10::Day10)),
-        11 => Some(Box::new(day11::Day11)),
-        12 => Some(Box::new(day12::Day12)),
-        _ => None,
-    }
+aoc_core::register_days! {
+    year: 2025,
+    day01::Day01 => "Secret Entrance",
+    day02::Day02 => "Gift Shop",
+    day03::Day03 => "Lobby",
+    day04::Day04 => "Printing Department",
+    day05::Day05 => "Cafeteria",
+    day06::Day06 => "Trash Compactor",
+    day07::Day07 => "Laboratories",
+    day08::Day08 => "Playground",
+    day09::Day09 => "Movie Theater",
+    day10::Day10 => "Factory",
+    day11::Day11 => "Reactor",
+    day12::Day12 => "Christmas Tree Farm",
 }